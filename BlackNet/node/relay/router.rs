@@ -1,22 +1,112 @@
-// This file contains the routing logic for the relay node. 
+// This file contains the routing logic for the relay node.
 
+use std::collections::HashSet;
 use std::net::{SocketAddr, UdpSocket};
-use std::thread;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+/// One NTP-style request/response round trip, timestamped with this node's
+/// own clock (`t0`/`t3`) and the server's clock (`t1`/`t2`).
+#[derive(Debug, Clone, Copy)]
+pub struct NtpSample {
+    pub t0: SystemTime,
+    pub t1: SystemTime,
+    pub t2: SystemTime,
+    pub t3: SystemTime,
+}
+
+impl NtpSample {
+    /// The standard NTP clock offset, in milliseconds, positive when the
+    /// server's clock is ahead of ours: `((t1 - t0) + (t2 - t3)) / 2`.
+    pub fn offset_millis(&self) -> i64 {
+        let forward = signed_millis(self.t1, self.t0);
+        let backward = signed_millis(self.t2, self.t3);
+        (forward + backward) / 2
+    }
+}
+
+/// `a - b` in milliseconds, signed, regardless of which is later.
+fn signed_millis(a: SystemTime, b: SystemTime) -> i64 {
+    match a.duration_since(b) {
+        Ok(d) => d.as_millis() as i64,
+        Err(e) => -(e.duration().as_millis() as i64),
+    }
+}
+
+/// Queries a single NTP server by address, or returns an error if it's
+/// unreachable. Injected rather than hitting a real socket so callers (and
+/// tests) can simulate servers deterministically.
+pub type NtpQuery = Arc<dyn Fn(&str) -> Result<NtpSample, String> + Send + Sync>;
+
+/// Snapshot of the router's operational state, for monitoring.
+#[derive(Debug, Clone)]
+pub struct HealthStatus {
+    pub offset_millis: Option<i64>,
+    pub last_successful_sync: Option<SystemTime>,
+    pub peer_count: usize,
+}
 
 pub struct Router {
     socket: UdpSocket,
+    ntp_servers: Vec<String>,
+    offset_millis: Option<i64>,
+    last_successful_sync: Option<SystemTime>,
+    peers: HashSet<SocketAddr>,
 }
 
 impl Router {
     pub fn new(addr: &str) -> Router {
         let socket = UdpSocket::bind(addr).expect("Could not bind socket");
-        Router { socket }
+        Router {
+            socket,
+            ntp_servers: Vec::new(),
+            offset_millis: None,
+            last_successful_sync: None,
+            peers: HashSet::new(),
+        }
+    }
+
+    /// Configures which NTP servers `sync_clock` polls.
+    pub fn set_ntp_servers(&mut self, servers: Vec<String>) {
+        self.ntp_servers = servers;
+    }
+
+    /// Polls every configured server via `query` and averages the
+    /// successful samples into a new offset estimate. Servers that error
+    /// are skipped; if every server is unreachable the previous offset
+    /// (if any) is left untouched rather than clearing a good estimate.
+    pub fn sync_clock(&mut self, query: &NtpQuery) -> Option<i64> {
+        let samples: Vec<NtpSample> = self
+            .ntp_servers
+            .iter()
+            .filter_map(|server| query(server).ok())
+            .collect();
+
+        if samples.is_empty() {
+            return self.offset_millis;
+        }
+
+        let total: i64 = samples.iter().map(NtpSample::offset_millis).sum();
+        let mean = total / samples.len() as i64;
+        self.offset_millis = Some(mean);
+        self.last_successful_sync = Some(SystemTime::now());
+        self.offset_millis
     }
 
-    pub fn start(&self) {
+    /// Current offset, last sync time, and peer count for monitoring.
+    pub fn health(&self) -> HealthStatus {
+        HealthStatus {
+            offset_millis: self.offset_millis,
+            last_successful_sync: self.last_successful_sync,
+            peer_count: self.peers.len(),
+        }
+    }
+
+    pub fn start(&mut self) {
         let mut buf = [0; 1024];
         loop {
             let (size, src) = self.socket.recv_from(&mut buf).expect("Failed to receive data");
+            self.peers.insert(src);
             self.route_packet(&buf[..size], src);
         }
     }
@@ -29,7 +119,70 @@ impl Router {
 }
 
 fn main() {
-    let router = Router::new("127.0.0.1:8080");
+    let mut router = Router::new("127.0.0.1:8080");
     println!("Router started on {}", "127.0.0.1:8080");
     router.start();
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn sample(offset_millis: i64) -> NtpSample {
+        let t0 = SystemTime::now();
+        let round_trip = Duration::from_millis(20);
+        let server_now = if offset_millis >= 0 {
+            t0 + Duration::from_millis(offset_millis as u64) + round_trip / 2
+        } else {
+            t0 - Duration::from_millis((-offset_millis) as u64) + round_trip / 2
+        };
+        NtpSample {
+            t0,
+            t1: server_now,
+            t2: server_now,
+            t3: t0 + round_trip,
+        }
+    }
+
+    #[test]
+    fn sync_clock_averages_reachable_servers() {
+        let mut router = Router::new("127.0.0.1:0");
+        router.set_ntp_servers(vec!["ntp-a".to_string(), "ntp-b".to_string()]);
+        let query: NtpQuery = Arc::new(|server: &str| match server {
+            "ntp-a" => Ok(sample(100)),
+            "ntp-b" => Ok(sample(300)),
+            _ => Err(format!("unknown server {}", server)),
+        });
+
+        let offset = router.sync_clock(&query);
+        assert!(offset.is_some());
+        assert!((offset.unwrap() - 200).abs() <= 1);
+
+        let health = router.health();
+        assert!((health.offset_millis.unwrap() - 200).abs() <= 1);
+        assert!(health.last_successful_sync.is_some());
+        assert_eq!(health.peer_count, 0);
+    }
+
+    #[test]
+    fn sync_clock_keeps_previous_offset_when_all_servers_unreachable() {
+        let mut router = Router::new("127.0.0.1:0");
+        router.set_ntp_servers(vec!["ntp-a".to_string()]);
+
+        // First establish a real, non-`None` offset from a reachable server.
+        let reachable: NtpQuery = Arc::new(|_server: &str| Ok(sample(150)));
+        let offset = router.sync_clock(&reachable);
+        assert!(offset.is_some());
+        assert!((offset.unwrap() - 150).abs() <= 1);
+        let synced_at = router.health().last_successful_sync;
+        assert!(synced_at.is_some());
+
+        // A later sync where every server is unreachable must keep that
+        // estimate rather than clearing it.
+        let unreachable: NtpQuery = Arc::new(|server: &str| Err(format!("timed out contacting {}", server)));
+        let offset_after_outage = router.sync_clock(&unreachable);
+        assert!((offset_after_outage.unwrap() - 150).abs() <= 1);
+        assert_eq!(router.health().last_successful_sync, synced_at);
+    }
+}
@@ -1,37 +1,231 @@
 // This file implements the server functionality for the SNApp host.
+//
+// Previously this answered every TCP request with a static "Hello" string.
+// It now runs an event-streaming service modeled on Iroha's
+// `Consumer`/`EventSubscriptionRequest`: a client opens a WebSocket, sends a
+// versioned `EventSubscriptionRequest` carrying a filter, and the server
+// pushes matching chain events as they occur instead of the client polling
+// `ava_blockchain_state.json`.
 
-use std::net::{TcpListener, TcpStream};
-use std::io::{Read, Write};
-use std::thread;
+use futures_util::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::broadcast;
+use tokio_tungstenite::tungstenite::Message;
 
-fn handle_client(mut stream: TcpStream) {
-    let mut buffer = [0; 512];
-    match stream.read(&mut buffer) {
-        Ok(_) => {
-            // Process the request and send a response
-            let response = "HTTP/1.1 200 OK\r\n\r\nHello from SNApp Host!";
-            stream.write(response.as_bytes()).unwrap();
+/// Chain activity a subscriber can be notified about
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Event {
+    BlockCommitted { index: u64, hash: String },
+    TransactionApplied { id: String, tx_type: String },
+    GovernanceAction { action_id: String, description: String },
+    EpochTransition { epoch: u64, validators: Vec<String> },
+}
+
+impl Event {
+    fn entity(&self) -> Option<&str> {
+        match self {
+            Event::TransactionApplied { id, .. } => Some(id.as_str()),
+            Event::GovernanceAction { action_id, .. } => Some(action_id.as_str()),
+            _ => None,
         }
-        Err(e) => {
-            eprintln!("Failed to read from client: {}", e);
+    }
+
+    fn transaction_type(&self) -> Option<&str> {
+        match self {
+            Event::TransactionApplied { tx_type, .. } => Some(tx_type.as_str()),
+            _ => None,
+        }
+    }
+
+    fn block_index(&self) -> Option<u64> {
+        match self {
+            Event::BlockCommitted { index, .. } => Some(*index),
+            _ => None,
+        }
+    }
+}
+
+/// Selects which events a `Consumer` forwards. Every field is additive:
+/// an unset field does not restrict the match, a set field must match.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EventFilter {
+    pub entity: Option<String>,
+    pub transaction_type: Option<String>,
+    pub block_range: Option<(u64, u64)>,
+}
+
+impl EventFilter {
+    pub fn matches(&self, event: &Event) -> bool {
+        if let Some(entity) = &self.entity {
+            if event.entity() != Some(entity.as_str()) {
+                return false;
+            }
+        }
+        if let Some(tx_type) = &self.transaction_type {
+            if event.transaction_type() != Some(tx_type.as_str()) {
+                return false;
+            }
         }
+        if let Some((start, end)) = self.block_range {
+            match event.block_index() {
+                Some(index) if index >= start && index <= end => {}
+                Some(_) => return false,
+                None => return false,
+            }
+        }
+        true
+    }
+}
+
+/// Versioned request a client sends right after the WebSocket handshake
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventSubscriptionRequest {
+    pub version: u8,
+    pub filter: EventFilter,
+}
+
+const PROTOCOL_VERSION: u8 = 1;
+
+/// Broadcasts chain events to every subscribed `Consumer`
+#[derive(Clone)]
+pub struct ChainEventBus {
+    sender: broadcast::Sender<Event>,
+}
+
+impl ChainEventBus {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _receiver) = broadcast::channel(capacity);
+        ChainEventBus { sender }
+    }
+
+    pub fn publish(&self, event: Event) {
+        // No subscribers is not an error; the event is simply dropped.
+        let _ = self.sender.send(event);
+    }
+
+    fn subscribe(&self) -> broadcast::Receiver<Event> {
+        self.sender.subscribe()
     }
 }
 
-fn main() {
-    let listener = TcpListener::bind("127.0.0.1:8080").expect("Could not bind to address");
-    println!("SNApp Host server running on port 8080");
+impl Default for ChainEventBus {
+    fn default() -> Self {
+        Self::new(1024)
+    }
+}
+
+/// Handles one subscribed client: reads its `EventSubscriptionRequest`,
+/// then forwards bus events that pass the filter until the socket closes.
+async fn handle_connection(stream: TcpStream, bus: ChainEventBus) {
+    let peer_addr = stream.peer_addr().ok();
+    let ws_stream = match tokio_tungstenite::accept_async(stream).await {
+        Ok(ws) => ws,
+        Err(e) => {
+            eprintln!("WebSocket handshake failed: {}", e);
+            return;
+        }
+    };
+
+    let (mut sink, mut source) = ws_stream.split();
 
-    for stream in listener.incoming() {
-        match stream {
-            Ok(stream) => {
-                thread::spawn(move || {
-                    handle_client(stream);
-                });
+    let filter = match source.next().await {
+        Some(Ok(Message::Text(text))) => match serde_json::from_str::<EventSubscriptionRequest>(&text) {
+            Ok(request) if request.version == PROTOCOL_VERSION => request.filter,
+            Ok(request) => {
+                eprintln!(
+                    "Rejecting subscription with unsupported version {}",
+                    request.version
+                );
+                let _ = sink.close().await;
+                return;
             }
             Err(e) => {
-                eprintln!("Connection failed: {}", e);
+                eprintln!("Malformed EventSubscriptionRequest: {}", e);
+                let _ = sink.close().await;
+                return;
             }
+        },
+        _ => {
+            eprintln!("Client {:?} disconnected before subscribing", peer_addr);
+            return;
         }
+    };
+
+    let mut receiver = bus.subscribe();
+    loop {
+        match receiver.recv().await {
+            Ok(event) if filter.matches(&event) => {
+                let payload = serde_json::to_string(&event).unwrap_or_default();
+                if sink.send(Message::Text(payload)).await.is_err() {
+                    break;
+                }
+            }
+            Ok(_) => continue,
+            Err(broadcast::error::RecvError::Lagged(_)) => continue,
+            Err(broadcast::error::RecvError::Closed) => break,
+        }
+    }
+}
+
+/// Run the SNApp host event-subscription server. `bus` is shared with
+/// whatever produces chain events (e.g. `AvaBlockchain::create_block`).
+pub async fn run(addr: &str, bus: ChainEventBus) -> std::io::Result<()> {
+    let listener = TcpListener::bind(addr).await?;
+    println!("SNApp Host event server running on {}", addr);
+
+    loop {
+        let (stream, _peer) = listener.accept().await?;
+        let bus = bus.clone();
+        tokio::spawn(async move {
+            handle_connection(stream, bus).await;
+        });
+    }
+}
+
+#[tokio::main]
+async fn main() -> std::io::Result<()> {
+    run("127.0.0.1:8080", ChainEventBus::default()).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter_matches_on_entity_and_transaction_type() {
+        let filter = EventFilter {
+            entity: Some("ava-core".to_string()),
+            transaction_type: Some("ModuleDeployment".to_string()),
+            block_range: None,
+        };
+
+        let matching = Event::TransactionApplied {
+            id: "ava-core".to_string(),
+            tx_type: "ModuleDeployment".to_string(),
+        };
+        let wrong_entity = Event::TransactionApplied {
+            id: "memory-core".to_string(),
+            tx_type: "ModuleDeployment".to_string(),
+        };
+
+        assert!(filter.matches(&matching));
+        assert!(!filter.matches(&wrong_entity));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn filter_matches_block_range() {
+        let filter = EventFilter {
+            entity: None,
+            transaction_type: None,
+            block_range: Some((5, 10)),
+        };
+
+        assert!(filter.matches(&Event::BlockCommitted { index: 7, hash: "h".to_string() }));
+        assert!(!filter.matches(&Event::BlockCommitted { index: 11, hash: "h".to_string() }));
+        assert!(!filter.matches(&Event::GovernanceAction {
+            action_id: "a".to_string(),
+            description: "d".to_string(),
+        }));
+    }
+}
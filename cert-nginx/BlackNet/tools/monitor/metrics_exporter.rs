@@ -1,31 +1,124 @@
 // This file implements the metrics exporter for monitoring the system.
+//
+// Beyond the generic request/response counters, it also tracks governance
+// activity -- proposals, votes, rule violations -- fed from
+// `ConstitutionalEngine`. That engine lives in a separate crate this one
+// doesn't depend on (the AVA-prototype chain crate), so `observe_engine`
+// and the `record_*` hooks take anything implementing `GovernanceSnapshot`
+// below rather than the concrete type; a binary wiring both crates
+// together would implement it for `ConstitutionalEngine` and call
+// `observe_engine` from `submit_proposal`/`cast_vote`/`tick`.
 
+use axum::{routing::get, Router};
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGauge, Opts, Registry, TextEncoder,
+};
+use std::net::SocketAddr;
+use std::sync::Arc;
 use std::time::Duration;
-use prometheus::{Encoder, IntCounter, IntGauge, Opts, Registry, TextEncoder};
 use tokio::time::interval;
 
+/// The governance-relevant state `observe_engine` reads off a
+/// `ConstitutionalEngine` to refresh the live gauges. Counters
+/// (`proposals_submitted`, `votes_cast`, ...) are driven by the
+/// `record_*` hooks instead, since they track transitions rather than a
+/// snapshot.
+pub trait GovernanceSnapshot {
+    fn active_proposal_count(&self) -> usize;
+}
+
 pub struct MetricsExporter {
     registry: Registry,
     request_counter: IntCounter,
     response_gauge: IntGauge,
+    /// Proposals currently in `Preparing`, `Deciding`, or `Confirming`.
+    active_proposals: IntGauge,
+    proposals_submitted: IntCounter,
+    proposals_approved: IntCounter,
+    proposals_rejected: IntCounter,
+    proposals_quorum_not_met: IntCounter,
+    /// Votes cast, labeled by `VoteType` ("for"/"against"/"abstain").
+    votes_cast: IntCounterVec,
+    /// Rule violations, labeled by `EnforcementLevel` ("advisory"/
+    /// "warning"/"blocking"/"constitutional").
+    rule_violations: IntCounterVec,
+    /// Distribution of `VotingRecord::participation_rate` across resolved
+    /// proposals.
+    participation_rate: HistogramVec,
 }
 
 impl MetricsExporter {
     pub fn new() -> Self {
         let registry = Registry::new();
-        
+
         let request_counter = IntCounter::with_opts(Opts::new("requests_total", "Total number of requests"))
             .expect("Failed to create request counter");
         let response_gauge = IntGauge::with_opts(Opts::new("responses_in_progress", "Number of responses in progress"))
             .expect("Failed to create response gauge");
+        let active_proposals = IntGauge::with_opts(Opts::new(
+            "governance_active_proposals",
+            "Proposals currently in Preparing, Deciding, or Confirming",
+        ))
+        .expect("Failed to create active proposals gauge");
+        let proposals_submitted = IntCounter::with_opts(Opts::new(
+            "governance_proposals_submitted_total",
+            "Total number of proposals submitted",
+        ))
+        .expect("Failed to create proposals submitted counter");
+        let proposals_approved = IntCounter::with_opts(Opts::new(
+            "governance_proposals_approved_total",
+            "Total number of proposals resolved Approved",
+        ))
+        .expect("Failed to create proposals approved counter");
+        let proposals_rejected = IntCounter::with_opts(Opts::new(
+            "governance_proposals_rejected_total",
+            "Total number of proposals resolved Rejected",
+        ))
+        .expect("Failed to create proposals rejected counter");
+        let proposals_quorum_not_met = IntCounter::with_opts(Opts::new(
+            "governance_proposals_quorum_not_met_total",
+            "Total number of proposals resolved TimedOut (quorum never met)",
+        ))
+        .expect("Failed to create proposals quorum-not-met counter");
+        let votes_cast = IntCounterVec::new(
+            Opts::new("governance_votes_cast_total", "Total number of votes cast, labeled by vote type"),
+            &["vote_type"],
+        )
+        .expect("Failed to create votes cast counter vec");
+        let rule_violations = IntCounterVec::new(
+            Opts::new("governance_rule_violations_total", "Total number of rule violations, labeled by enforcement level"),
+            &["enforcement_level"],
+        )
+        .expect("Failed to create rule violations counter vec");
+        let participation_rate = HistogramVec::new(
+            HistogramOpts::new("governance_proposal_participation_rate", "Per-proposal participation rate at resolution"),
+            &["proposal_id"],
+        )
+        .expect("Failed to create participation rate histogram");
 
         registry.register(Box::new(request_counter.clone())).unwrap();
         registry.register(Box::new(response_gauge.clone())).unwrap();
+        registry.register(Box::new(active_proposals.clone())).unwrap();
+        registry.register(Box::new(proposals_submitted.clone())).unwrap();
+        registry.register(Box::new(proposals_approved.clone())).unwrap();
+        registry.register(Box::new(proposals_rejected.clone())).unwrap();
+        registry.register(Box::new(proposals_quorum_not_met.clone())).unwrap();
+        registry.register(Box::new(votes_cast.clone())).unwrap();
+        registry.register(Box::new(rule_violations.clone())).unwrap();
+        registry.register(Box::new(participation_rate.clone())).unwrap();
 
         MetricsExporter {
             registry,
             request_counter,
             response_gauge,
+            active_proposals,
+            proposals_submitted,
+            proposals_approved,
+            proposals_rejected,
+            proposals_quorum_not_met,
+            votes_cast,
+            rule_violations,
+            participation_rate,
         }
     }
 
@@ -37,20 +130,207 @@ impl MetricsExporter {
         self.response_gauge.set(count);
     }
 
-    pub async fn start_exporting(&self) {
-        let mut interval = interval(Duration::from_secs(10));
-        loop {
-            interval.tick().await;
-            self.export_metrics().await;
+    /// Refreshes the gauges that reflect current governance state. Call
+    /// after every engine mutation, or on the same tick as
+    /// `ConstitutionalEngine::tick`.
+    pub fn observe_engine(&self, engine: &impl GovernanceSnapshot) {
+        self.active_proposals.set(engine.active_proposal_count() as i64);
+    }
+
+    /// Call from `submit_proposal` once a proposal is accepted.
+    pub fn record_proposal_submitted(&self) {
+        self.proposals_submitted.inc();
+    }
+
+    /// Call from `cast_vote` once a vote is recorded, labeled by its
+    /// `VoteType` ("for"/"against"/"abstain").
+    pub fn record_vote_cast(&self, vote_type: &str) {
+        self.votes_cast.with_label_values(&[vote_type]).inc();
+    }
+
+    /// Call from `advance` (run by `tick`) or `tally` when a proposal
+    /// resolves, labeled by its `VoteResult` ("passed"/
+    /// "failed"/"quorum_not_met") and carrying the final
+    /// `VotingRecord::participation_rate`.
+    pub fn record_proposal_resolved(&self, proposal_id: &str, result: &str, participation_rate: f64) {
+        match result {
+            "passed" => self.proposals_approved.inc(),
+            "failed" => self.proposals_rejected.inc(),
+            "quorum_not_met" => self.proposals_quorum_not_met.inc(),
+            _ => {}
         }
+        self.participation_rate.with_label_values(&[proposal_id]).observe(participation_rate);
+    }
+
+    /// Call wherever a `RuleViolation` is raised, labeled by the
+    /// violating rule's `EnforcementLevel` ("advisory"/"warning"/
+    /// "blocking"/"constitutional").
+    pub fn record_rule_violation(&self, enforcement_level: &str) {
+        self.rule_violations.with_label_values(&[enforcement_level]).inc();
     }
 
-    async fn export_metrics(&self) {
+    fn encode(&self) -> String {
         let encoder = TextEncoder::new();
         let metric_families = self.registry.gather();
         let mut buffer = Vec::new();
         encoder.encode(&metric_families, &mut buffer).unwrap();
-        let metrics = String::from_utf8(buffer).unwrap();
-        println!("{}", metrics); // Replace with actual export logic (e.g., HTTP endpoint)
+        String::from_utf8(buffer).unwrap()
     }
-}
\ No newline at end of file
+
+    /// Serves the registry's current state as `text/plain` on `/metrics`
+    /// at `addr`, for Prometheus to scrape. Runs until the process exits.
+    pub async fn serve(self: Arc<Self>, addr: SocketAddr) -> std::io::Result<()> {
+        let app = Router::new().route(
+            "/metrics",
+            get({
+                let exporter = self.clone();
+                move || {
+                    let exporter = exporter.clone();
+                    async move { exporter.encode() }
+                }
+            }),
+        );
+
+        let listener = tokio::net::TcpListener::bind(addr).await?;
+        axum::serve(listener, app).await
+    }
+
+    /// Periodically logs the registry to stdout; useful for local
+    /// debugging without a scraper, distinct from `serve`'s HTTP endpoint.
+    pub async fn start_exporting(&self) {
+        let mut interval = interval(Duration::from_secs(10));
+        loop {
+            interval.tick().await;
+            println!("{}", self.encode());
+        }
+    }
+}
+
+impl Default for MetricsExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prometheus::proto::MetricFamily;
+
+    /// A fixed-count stand-in for `ConstitutionalEngine`, since this crate
+    /// doesn't depend on it -- mirrors `InMemoryGovernanceStore` in
+    /// `quantum-engine`'s `graphql.rs`, the other trait-decoupled adapter
+    /// point onto the same engine.
+    struct FakeGovernanceSnapshot {
+        active: usize,
+    }
+
+    impl GovernanceSnapshot for FakeGovernanceSnapshot {
+        fn active_proposal_count(&self) -> usize {
+            self.active
+        }
+    }
+
+    fn family(exporter: &MetricsExporter, name: &str) -> MetricFamily {
+        exporter
+            .registry
+            .gather()
+            .into_iter()
+            .find(|family| family.get_name() == name)
+            .unwrap_or_else(|| panic!("metric {} was never registered", name))
+    }
+
+    fn gauge_value(exporter: &MetricsExporter, name: &str) -> f64 {
+        family(exporter, name).get_metric()[0].get_gauge().get_value()
+    }
+
+    fn counter_value(exporter: &MetricsExporter, name: &str, label_value: Option<&str>) -> f64 {
+        let metrics = exporter.registry.gather();
+        let metric_family = metrics
+            .iter()
+            .find(|family| family.get_name() == name)
+            .unwrap_or_else(|| panic!("metric {} was never registered", name));
+        let metric = match label_value {
+            None => &metric_family.get_metric()[0],
+            Some(value) => metric_family
+                .get_metric()
+                .iter()
+                .find(|m| m.get_label().iter().any(|l| l.get_value() == value))
+                .unwrap_or_else(|| panic!("metric {} has no series labeled {}", name, value)),
+        };
+        metric.get_counter().get_value()
+    }
+
+    #[test]
+    fn observe_engine_sets_the_active_proposals_gauge() {
+        let exporter = MetricsExporter::new();
+        exporter.observe_engine(&FakeGovernanceSnapshot { active: 3 });
+        assert_eq!(gauge_value(&exporter, "governance_active_proposals"), 3.0);
+
+        exporter.observe_engine(&FakeGovernanceSnapshot { active: 0 });
+        assert_eq!(gauge_value(&exporter, "governance_active_proposals"), 0.0);
+    }
+
+    #[test]
+    fn record_proposal_submitted_increments_the_counter() {
+        let exporter = MetricsExporter::new();
+        exporter.record_proposal_submitted();
+        exporter.record_proposal_submitted();
+        assert_eq!(
+            counter_value(&exporter, "governance_proposals_submitted_total", None),
+            2.0
+        );
+    }
+
+    #[test]
+    fn record_vote_cast_increments_the_matching_label() {
+        let exporter = MetricsExporter::new();
+        exporter.record_vote_cast("for");
+        exporter.record_vote_cast("for");
+        exporter.record_vote_cast("against");
+
+        assert_eq!(
+            counter_value(&exporter, "governance_votes_cast_total", Some("for")),
+            2.0
+        );
+        assert_eq!(
+            counter_value(&exporter, "governance_votes_cast_total", Some("against")),
+            1.0
+        );
+    }
+
+    #[test]
+    fn record_proposal_resolved_routes_each_result_to_its_own_counter() {
+        let exporter = MetricsExporter::new();
+        exporter.record_proposal_resolved("prop_a", "passed", 0.8);
+        exporter.record_proposal_resolved("prop_b", "failed", 0.5);
+        exporter.record_proposal_resolved("prop_c", "quorum_not_met", 0.1);
+
+        assert_eq!(counter_value(&exporter, "governance_proposals_approved_total", None), 1.0);
+        assert_eq!(counter_value(&exporter, "governance_proposals_rejected_total", None), 1.0);
+        assert_eq!(
+            counter_value(&exporter, "governance_proposals_quorum_not_met_total", None),
+            1.0
+        );
+
+        let participation = family(&exporter, "governance_proposal_participation_rate");
+        assert_eq!(participation.get_metric().len(), 3);
+    }
+
+    #[test]
+    fn record_rule_violation_increments_the_matching_label() {
+        let exporter = MetricsExporter::new();
+        exporter.record_rule_violation("blocking");
+        exporter.record_rule_violation("blocking");
+        exporter.record_rule_violation("advisory");
+
+        assert_eq!(
+            counter_value(&exporter, "governance_rule_violations_total", Some("blocking")),
+            2.0
+        );
+        assert_eq!(
+            counter_value(&exporter, "governance_rule_violations_total", Some("advisory")),
+            1.0
+        );
+    }
+}
@@ -0,0 +1,144 @@
+// Parallel Block Verification Queue
+// Sits between block/transaction ingest and `AvaBlockchain` import, modeled
+// on OpenEthereum's `BlockQueue`: a pool of worker threads verifies
+// incoming blocks off the import thread so the chain can scale beyond
+// single-threaded mining. The concurrency machinery itself lives in
+// `crate::verify_queue::VerifyQueue`, shared with `TransactionQueue`; this
+// is just the `Block`-typed façade over it.
+
+use crate::blockchain::Block;
+use crate::verify_queue::VerifyQueue;
+use std::sync::Arc;
+
+pub use crate::verify_queue::QueueInfo;
+pub(crate) use crate::verify_queue::worker_count;
+
+/// Signature of the verification function run by every worker: hash
+/// recomputation, signature checks, and constitutional-rule validation
+/// live here, supplied by whoever owns the queue (usually `AvaBlockchain`).
+pub type BlockVerifier = Arc<dyn Fn(&Block) -> Result<(), String> + Send + Sync>;
+
+/// Concurrent, typed block-verification pipeline: `unverified -> verifying
+/// -> verified`. Import only ever pulls from `verified`, in arrival order.
+pub struct BlockQueue(VerifyQueue<Block>);
+
+impl BlockQueue {
+    /// Spawn a queue backed by `max(available_parallelism, 3) - 2` worker
+    /// threads, each running `verifier` against popped blocks.
+    pub fn new(verifier: BlockVerifier) -> Self {
+        Self::with_worker_count(worker_count(), verifier)
+    }
+
+    pub fn with_worker_count(workers: usize, verifier: BlockVerifier) -> Self {
+        BlockQueue(VerifyQueue::with_worker_count(
+            workers,
+            verifier,
+            Arc::new(|block: &Block| block.hash.clone()),
+        ))
+    }
+
+    /// Enqueue a block for verification. A block whose hash is already
+    /// queued, in flight, or verified is silently ignored.
+    pub fn push(&self, block: Block) {
+        self.0.push(block)
+    }
+
+    /// Drain every verified block, in the order it was originally pushed
+    pub fn drain_verified(&self) -> Vec<Block> {
+        self.0.drain_verified()
+    }
+
+    pub fn info(&self) -> QueueInfo {
+        self.0.info()
+    }
+
+    pub fn total_queue_size(&self) -> usize {
+        self.info().total_queue_size()
+    }
+
+    pub fn incomplete_queue_size(&self) -> usize {
+        self.info().incomplete_queue_size()
+    }
+
+    /// Block the calling thread until no work remains unverified or
+    /// in-flight (verified results may still be waiting to be drained)
+    pub fn wait_until_empty(&self) {
+        self.0.wait_until_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blockchain::{hash_block, BlockData};
+    use chrono::Utc;
+
+    fn sample_block(index: u64, previous_hash: &str) -> Block {
+        let mut block = Block {
+            index,
+            timestamp: Utc::now(),
+            previous_hash: previous_hash.to_string(),
+            hash: String::new(),
+            data: BlockData {
+                transactions: Vec::new(),
+                entity_metadata: None,
+                constitutional_rules: Vec::new(),
+                governance_actions: Vec::new(),
+                epoch_proof: None,
+                aborted_transaction_ids: Vec::new(),
+            },
+            nonce: 0,
+            difficulty: 0,
+            signatures: Vec::new(),
+            transactions_root: String::new(),
+        };
+        block.hash = hash_block(&block);
+        block
+    }
+
+    #[test]
+    fn verified_blocks_drain_in_arrival_order() {
+        let verifier: BlockVerifier = Arc::new(|_block: &Block| Ok(()));
+        let queue = BlockQueue::with_worker_count(2, verifier);
+
+        let b0 = sample_block(0, "genesis");
+        let b1 = sample_block(1, &b0.hash);
+        queue.push(b0.clone());
+        queue.push(b1.clone());
+
+        queue.wait_until_empty();
+        let verified = queue.drain_verified();
+
+        assert_eq!(verified.len(), 2);
+        assert_eq!(verified[0].hash, b0.hash);
+        assert_eq!(verified[1].hash, b1.hash);
+    }
+
+    #[test]
+    fn failed_verification_never_reaches_verified() {
+        let verifier: BlockVerifier = Arc::new(|block: &Block| {
+            if block.index == 0 {
+                Ok(())
+            } else {
+                Err("bad block".to_string())
+            }
+        });
+        let queue = BlockQueue::with_worker_count(1, verifier);
+        queue.push(sample_block(1, "whatever"));
+        queue.wait_until_empty();
+
+        assert_eq!(queue.drain_verified().len(), 0);
+    }
+
+    #[test]
+    fn duplicate_hashes_are_deduped() {
+        let verifier: BlockVerifier = Arc::new(|_block: &Block| Ok(()));
+        let queue = BlockQueue::with_worker_count(1, verifier);
+        let block = sample_block(0, "genesis");
+        queue.push(block.clone());
+        queue.push(block);
+        queue.wait_until_empty();
+
+        assert_eq!(queue.drain_verified().len(), 1);
+    }
+}
@@ -4,9 +4,23 @@
 use sha2::{Sha256, Digest};
 use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 
+use crate::block_queue::{BlockQueue, BlockVerifier, QueueInfo};
+use crate::engine::{ConsensusEngine, PowEngine};
+use crate::events::{ChainEvent, EventBus};
+use crate::merkle::{self, InclusionProof};
+use crate::network_time::NetworkTimeSync;
+use crate::schnorr::{self, GovernancePublicKey, SchnorrSignature, SchnorrVerifier};
+use crate::tx_queue::{TransactionQueue, TransactionVerifier};
+use chrono::Duration;
+use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT;
+use curve25519_dalek::ristretto::CompressedRistretto;
+use curve25519_dalek::scalar::Scalar;
+use rand::Rng;
+use std::sync::Arc;
+
 /// Block structure for the immutable chain
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Block {
@@ -17,6 +31,135 @@ pub struct Block {
     pub data: BlockData,
     pub nonce: u64,
     pub difficulty: u32,
+    /// Consensus-engine-specific seal material: empty for `PowEngine`,
+    /// a single maintainer signature for `BasicAuthority`, or one entry
+    /// per co-signing container for `QuorumBft`.
+    #[serde(default)]
+    pub signatures: Vec<String>,
+    /// Root of the Merkle tree over `data.transactions` (see
+    /// `transactions_merkle_root`), hex-encoded. Lets `prove_transaction`
+    /// hand a light client a logarithmic-size proof that a transaction was
+    /// included without shipping the whole block.
+    #[serde(default)]
+    pub transactions_root: String,
+}
+
+/// Hashes a transaction's canonical bytes into the Merkle leaf `verify_inclusion`
+/// expects as `tx_leaf`, so a light client holding just the transaction and
+/// a proof can reconstruct the same leaf this module folds into the tree.
+pub fn transaction_leaf(transaction: &Transaction) -> [u8; 32] {
+    merkle::leaf_hash(&serde_json::to_vec(transaction).unwrap_or_default())
+}
+
+/// Hashes each transaction's canonical bytes into a Merkle leaf, in block
+/// order, for `transactions_merkle_root` and `AvaBlockchain::prove_transaction`
+/// to fold into a tree over.
+fn transaction_leaves(transactions: &[Transaction]) -> Vec<[u8; 32]> {
+    transactions.iter().map(transaction_leaf).collect()
+}
+
+/// Computes the hex-encoded Merkle root over `transactions`, the value
+/// stored in `Block::transactions_root`.
+pub fn transactions_merkle_root(transactions: &[Transaction]) -> String {
+    hex::encode(merkle::merkle_root(&transaction_leaves(transactions)))
+}
+
+/// Hash a block's contents the same way regardless of which
+/// `ConsensusEngine` is sealing or verifying it.
+pub fn hash_block(block: &Block) -> String {
+    let mut hasher = Sha256::new();
+    let data = format!(
+        "{}{}{}{}{}{}{}",
+        block.index,
+        block.timestamp.timestamp(),
+        block.previous_hash,
+        serde_json::to_string(&block.data).unwrap_or_default(),
+        block.nonce,
+        block.difficulty,
+        block.transactions_root
+    );
+    hasher.update(data.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// The verification every queued block gets regardless of consensus
+/// engine: hash integrity plus well-formed transactions. Engine-specific
+/// seal verification (PoW target, authority signature, quorum) happens
+/// separately when the block is admitted onto the chain, since it needs
+/// the parent block for context.
+fn default_import_verifier() -> BlockVerifier {
+    Arc::new(|block: &Block| {
+        if hash_block(block) != block.hash {
+            return Err(format!("block {} hash does not match contents", block.index));
+        }
+        for transaction in &block.data.transactions {
+            if transaction.id.is_empty() {
+                return Err("transaction with empty id".to_string());
+            }
+            if transaction.signature.is_empty() {
+                return Err(format!("transaction {} is missing a signature", transaction.id));
+            }
+        }
+        Ok(())
+    })
+}
+
+/// The verification every queued transaction gets before mining: basic
+/// well-formedness, independent of the constitutional rule checks
+/// `validate_transaction` runs when a transaction is first submitted.
+fn default_transaction_verifier() -> TransactionVerifier {
+    Arc::new(|transaction: &Transaction| {
+        if transaction.id.is_empty() {
+            return Err("transaction id cannot be empty".to_string());
+        }
+        if transaction.signature.is_empty() {
+            return Err(format!("transaction {} is missing a signature", transaction.id));
+        }
+        Ok(())
+    })
+}
+
+/// Whether `transaction_type` carries enough authority (proposal,
+/// amendment, funding, or container registration) that it must be
+/// co-signed by a quorum of the governance council before it reaches the
+/// mempool, rather than merely carrying `constitutional_validation: true`.
+pub fn requires_governance_authorization(transaction_type: &TransactionType) -> bool {
+    matches!(
+        transaction_type,
+        TransactionType::GovernanceProposal
+            | TransactionType::ConstitutionalAmendment
+            | TransactionType::ResourceAllocation
+            | TransactionType::ModuleDeployment
+    )
+}
+
+/// The exact bytes a `GovernanceAuthorization` signs: the transaction's
+/// `id`, `transaction_type`, `sender`, `recipient`, `data` and `timestamp`
+/// (whole seconds). `signature`, `constitutional_validation`,
+/// `governance_authorization`, `authenticator` and `identity_proof` are not
+/// covered. The signing side and `AvaBlockchain::add_transaction` both build
+/// the bytes here, so they always agree on what was actually signed.
+pub fn governance_signing_payload(transaction: &Transaction) -> Vec<u8> {
+    #[derive(Serialize)]
+    struct SigningPayload<'a> {
+        id: &'a str,
+        transaction_type: &'a TransactionType,
+        sender: &'a str,
+        recipient: &'a Option<String>,
+        data: &'a serde_json::Value,
+        timestamp: i64,
+    }
+
+    let payload = SigningPayload {
+        id: &transaction.id,
+        transaction_type: &transaction.transaction_type,
+        sender: &transaction.sender,
+        recipient: &transaction.recipient,
+        data: &transaction.data,
+        timestamp: transaction.timestamp.timestamp(),
+    };
+
+    serde_json::to_vec(&payload).unwrap_or_default()
 }
 
 /// Block data containing transactions and metadata
@@ -26,6 +169,15 @@ pub struct BlockData {
     pub entity_metadata: Option<EntityMetadata>,
     pub constitutional_rules: Vec<Rule>,
     pub governance_actions: Vec<GovernanceAction>,
+    /// Present only on the block that records a validator-set epoch
+    /// transition (see `crate::validator_set`)
+    #[serde(default)]
+    pub epoch_proof: Option<crate::validator_set::EpochProof>,
+    /// Ids of transactions that were in `pending_transactions` when
+    /// `create_block` ran but failed re-validation at mining time (e.g. a
+    /// revoked parent approval) and so were dropped instead of committed.
+    #[serde(default)]
+    pub aborted_transaction_ids: Vec<String>,
 }
 
 /// Transaction structure for constitutional operations
@@ -39,10 +191,120 @@ pub struct Transaction {
     pub timestamp: DateTime<Utc>,
     pub signature: String,
     pub constitutional_validation: bool,
+    /// Aggregated MuSig-style Schnorr authorization from the governance
+    /// council, required for `transaction_type`s that
+    /// `requires_governance_authorization` flags and verified by
+    /// `AvaBlockchain::add_transaction` before the transaction is admitted
+    /// to the mempool. `None` for transactions that don't need it.
+    #[serde(default)]
+    pub governance_authorization: Option<GovernanceAuthorization>,
+    /// Real cryptographic signature(s) over this transaction, checked by
+    /// `verify_signature` and enforced by `validate_transaction` for
+    /// `Rule::RequiresSignature`/`Rule::ConsensusRequired`. `None` for
+    /// transactions that only carry the legacy `signature` placeholder.
+    #[serde(default)]
+    pub authenticator: Option<TransactionAuthenticator>,
+    /// A zk-SNARK proof of knowledge of an identity preimage, checked by
+    /// `validate_transaction` for `Rule::AIValidation` on `EntityRegistration`
+    /// transactions via `AvaBlockchain::identity_verifier`. Opaque proof
+    /// bytes from the membership circuit in `zk_identity::snark_id`, which
+    /// `crate::identity_verifier` includes through a `#[path]` module; `None`
+    /// for transactions that don't carry one.
+    #[serde(default)]
+    pub identity_proof: Option<Vec<u8>>,
 }
 
-/// Types of transactions in the system
+/// A real cryptographic signature over a transaction's canonical signing
+/// payload (`governance_signing_payload`), as opposed to the free-form
+/// `signature` string placeholder.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TransactionAuthenticator {
+    Ed25519 {
+        public_key: [u8; 32],
+        signature: [u8; 64],
+    },
+    MultiEd25519 {
+        public_keys: Vec<[u8; 32]>,
+        signatures: Vec<[u8; 64]>,
+        threshold: usize,
+    },
+}
+
+/// Checks one Ed25519 signature over `message`, folding a malformed key or
+/// signature into `false` rather than propagating an error.
+fn verify_ed25519(public_key: &[u8; 32], signature: &[u8; 64], message: &[u8]) -> bool {
+    let Ok(verifying_key) = ed25519_dalek::VerifyingKey::from_bytes(public_key) else {
+        return false;
+    };
+    let signature = ed25519_dalek::Signature::from_bytes(signature);
+    verifying_key.verify_strict(message, &signature).is_ok()
+}
+
+impl Transaction {
+    /// Signs this transaction's canonical payload with `key`, replacing
+    /// any existing `authenticator` with a single-signer `Ed25519`.
+    pub fn sign(&mut self, key: &ed25519_dalek::SigningKey) {
+        use ed25519_dalek::Signer;
+
+        let message = governance_signing_payload(self);
+        let signature = key.sign(&message);
+        self.authenticator = Some(TransactionAuthenticator::Ed25519 {
+            public_key: key.verifying_key().to_bytes(),
+            signature: signature.to_bytes(),
+        });
+    }
+
+    /// Whether `authenticator` carries enough valid signatures over the
+    /// canonical payload: any valid signature for `Ed25519`, or at least
+    /// `threshold` valid signatures for `MultiEd25519`.
+    pub fn verify_signature(&self) -> bool {
+        match &self.authenticator {
+            Some(TransactionAuthenticator::Ed25519 { .. }) => !self.verified_signer_keys().is_empty(),
+            Some(TransactionAuthenticator::MultiEd25519 { threshold, .. }) => {
+                self.verified_signer_keys().len() >= *threshold
+            }
+            None => false,
+        }
+    }
+
+    /// Hex-encoded public keys in `authenticator` whose signature over the
+    /// canonical payload actually verifies, used to check `authenticator`
+    /// covers a specific key (`Rule::RequiresSignature`) or a set of
+    /// entities' registered keys (`Rule::ConsensusRequired`).
+    fn verified_signer_keys(&self) -> Vec<String> {
+        let message = governance_signing_payload(self);
+        match &self.authenticator {
+            Some(TransactionAuthenticator::Ed25519 { public_key, signature }) => {
+                if verify_ed25519(public_key, signature, &message) {
+                    vec![hex::encode(public_key)]
+                } else {
+                    Vec::new()
+                }
+            }
+            Some(TransactionAuthenticator::MultiEd25519 { public_keys, signatures, .. }) => public_keys
+                .iter()
+                .zip(signatures.iter())
+                .filter(|(public_key, signature)| verify_ed25519(public_key, signature, &message))
+                .map(|(public_key, _)| hex::encode(public_key))
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+}
+
+/// An aggregated Schnorr signature `(r, s)` over a governance transaction
+/// plus the compressed council public keys that co-signed it, so
+/// `AvaBlockchain` can re-derive the MuSig-aggregated key and verify the
+/// signature without trusting the sender's claim alone.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceAuthorization {
+    pub signers: Vec<[u8; 32]>,
+    pub r: [u8; 32],
+    pub s: [u8; 32],
+}
+
+/// Types of transactions in the system
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum TransactionType {
     GenesisCreation,
     EntityRegistration,
@@ -54,6 +316,9 @@ pub enum TransactionType {
     AIModelUpdate,
     PermissionGrant,
     EmergencyAction,
+    /// Adds or removes a container from the validator set, triggering an
+    /// epoch transition (see `crate::validator_set`)
+    GovernanceAction,
 }
 
 /// Entity metadata for organizations and modules
@@ -69,6 +334,11 @@ pub struct EntityMetadata {
     pub parent_entity: Option<String>,
     pub child_entities: Vec<String>,
     pub constitutional_constraints: Vec<Rule>,
+    /// Hex-encoded Ed25519 public keys authorized to co-sign transactions
+    /// on this entity's behalf, checked by `Rule::ConsensusRequired`.
+    /// Distinct from `maintainers`, which are human-readable contacts.
+    #[serde(default)]
+    pub signing_keys: Vec<String>,
 }
 
 /// Types of entities in the system
@@ -110,7 +380,7 @@ pub struct GovernanceAction {
 }
 
 /// Types of governance actions
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum GovernanceActionType {
     PolicyUpdate,
     ResourceAllocation,
@@ -131,23 +401,165 @@ pub enum ActionStatus {
     Cancelled,
 }
 
+/// Checks a zk-SNARK identity proof against a commitment, decoupling
+/// `validate_transaction`'s `Rule::AIValidation` handling from any one
+/// proving system the same way `ConsensusEngine` decouples sealing from any
+/// one consensus mechanism. The membership circuit that proves knowledge of
+/// an identity preimage without revealing it lives in the separate
+/// `zk_identity::snark_id` module; `identity_verifier::Groth16IdentityVerifier`
+/// wraps its `VerifyingKey` and calls its `verify_proof`.
+pub trait IdentityProofVerifier: Send + Sync {
+    /// Returns whether `proof` is a valid zk-SNARK proof of knowledge of a
+    /// preimage whose hash is `commitment` (hex-encoded, matching the hex
+    /// string carried by `Rule::AIValidation`).
+    fn verify(&self, commitment: &str, proof: &[u8]) -> bool;
+}
+
 /// Main blockchain implementation
 pub struct AvaBlockchain {
     pub chain: Vec<Block>,
     pub pending_transactions: Vec<Transaction>,
     pub entities: HashMap<String, EntityMetadata>,
     pub genesis_created: bool,
+    /// Seals and verifies every block; defaults to `PowEngine` but can be
+    /// swapped for `BasicAuthority`/`QuorumBft` via `with_engine`.
+    pub engine: Box<dyn ConsensusEngine>,
+    /// Staging area between ingest and the canonical `chain`: blocks
+    /// received from outside (peers, snapshot restore) are pushed here and
+    /// verified off the import thread before `import_queued_blocks` admits
+    /// them, in order.
+    pub import_queue: BlockQueue,
+    /// Staging area between `add_transaction` and mining: pending
+    /// transactions are verified off the mining thread, using all cores,
+    /// before `mine_pending_transactions` seals them into a block.
+    pub tx_queue: TransactionQueue,
+    /// Manifest hashes of snapshots that failed restore, so a bad snapshot
+    /// is never retried in a loop
+    pub blacklisted_manifests: HashSet<String>,
+    /// Compressed public keys of the governance council authorized to
+    /// co-sign transactions flagged by `requires_governance_authorization`,
+    /// set via `set_governance_council`.
+    pub governance_council: HashSet<[u8; 32]>,
+    /// Minimum distinct council signers a `GovernanceAuthorization` must
+    /// carry before it is even considered, set via
+    /// `set_governance_threshold`. Defaults to `1`, so a freshly constructed
+    /// chain behaves as before until the operator opts into a higher quorum.
+    pub governance_threshold: usize,
+    /// Network-time offset tracker used to stamp mined blocks and to bound
+    /// `max_clock_drift` checks on blocks admitted from `import_queue`.
+    pub clock: NetworkTimeSync,
+    /// How far a block's timestamp may diverge from `clock`'s network time
+    /// before `import_queued_blocks` rejects it.
+    pub max_clock_drift: Duration,
+    /// Checks `Transaction::identity_proof` against a `Rule::AIValidation`
+    /// commitment, set via `set_identity_verifier`. `None` until configured,
+    /// in which case `Rule::AIValidation` rejects any transaction it
+    /// applies to rather than silently passing it.
+    pub identity_verifier: Option<Box<dyn IdentityProofVerifier>>,
+    /// Fan-out point for chain activity: `create_block` publishes
+    /// `BlockAdded`/`TransactionIncluded`/`EntityRegistered`/
+    /// `GovernanceActionStatusChanged` here as blocks are mined, and
+    /// containers `subscribe` with an `EventFilter` to react in real time
+    /// instead of polling `get_status`.
+    pub events: EventBus,
 }
 
 impl AvaBlockchain {
-    /// Create a new blockchain instance
+    /// Create a new blockchain instance using the default PoW engine
     pub fn new() -> Self {
+        AvaBlockchain::with_engine(Box::new(PowEngine))
+    }
+
+    /// Create a new blockchain instance sealed/verified by `engine`
+    pub fn with_engine(engine: Box<dyn ConsensusEngine>) -> Self {
         AvaBlockchain {
             chain: Vec::new(),
             pending_transactions: Vec::new(),
             entities: HashMap::new(),
             genesis_created: false,
+            engine,
+            import_queue: BlockQueue::new(default_import_verifier()),
+            tx_queue: TransactionQueue::new(default_transaction_verifier()),
+            blacklisted_manifests: HashSet::new(),
+            governance_council: HashSet::new(),
+            governance_threshold: 1,
+            clock: NetworkTimeSync::disabled(),
+            max_clock_drift: Duration::seconds(30),
+            identity_verifier: None,
+            events: EventBus::new(),
+        }
+    }
+
+    /// Register the compressed public keys eligible to co-sign governance
+    /// transactions. Replaces any previously registered council.
+    pub fn set_governance_council(&mut self, keys: impl IntoIterator<Item = [u8; 32]>) {
+        self.governance_council = keys.into_iter().collect();
+    }
+
+    /// Set the minimum number of distinct council members that must co-sign
+    /// a `GovernanceAuthorization` for it to be accepted. A single signer
+    /// (the default) means any one council key can authorize a governance
+    /// transaction alone; raise this to require an actual quorum.
+    pub fn set_governance_threshold(&mut self, threshold: usize) {
+        self.governance_threshold = threshold;
+    }
+
+    /// Install the verifier `Rule::AIValidation` checks zk identity proofs
+    /// against. Replaces any previously configured verifier.
+    pub fn set_identity_verifier(&mut self, verifier: Box<dyn IdentityProofVerifier>) {
+        self.identity_verifier = Some(verifier);
+    }
+
+    /// Reject a block whose timestamp has drifted from the current network
+    /// time (per `clock`) by more than `max_clock_drift` in either
+    /// direction. While `clock` is `Unsynced`, network time falls back to
+    /// this node's own wall clock, so drift is still bounded against
+    /// gross clock errors rather than left unchecked.
+    fn validate_block_timestamp(&self, block: &Block) -> Result<(), String> {
+        let network_now = self.clock.network_time(Utc::now());
+        let drift = block.timestamp - network_now;
+        if drift > self.max_clock_drift || drift < -self.max_clock_drift {
+            return Err(format!(
+                "block {} timestamp drifts {}ms from network time (bound is {}ms)",
+                block.index,
+                drift.num_milliseconds(),
+                self.max_clock_drift.num_milliseconds()
+            ));
         }
+        Ok(())
+    }
+
+    /// Queue an externally-sourced block for parallel verification rather
+    /// than importing it directly
+    pub fn queue_block_for_import(&mut self, block: Block) {
+        self.import_queue.push(block);
+    }
+
+    /// Pull every fully-verified block off the import queue, in the order
+    /// it was originally queued, and admit it onto the canonical chain
+    /// only if it still links onto the current tip
+    pub fn import_queued_blocks(&mut self) -> Result<usize, String> {
+        let mut imported = 0;
+        for block in self.import_queue.drain_verified() {
+            let tip = self.chain.last().ok_or("Cannot import before genesis")?;
+            self.engine.verify_seal(&block, tip)?;
+            self.validate_block_timestamp(&block)?;
+            self.chain.push(block);
+            imported += 1;
+        }
+        Ok(imported)
+    }
+
+    /// Current state of the import queue's three stages
+    pub fn import_queue_info(&self) -> QueueInfo {
+        self.import_queue.info()
+    }
+
+    /// Poll every configured NTP server and fold the result into `clock`'s
+    /// offset/drift estimate, used to stamp the next mined block and to
+    /// bound `import_queued_blocks`'s drift check.
+    pub fn sync_clock(&mut self) -> crate::network_time::ClockStatus {
+        self.clock.sync(Utc::now())
     }
 
     /// Create the dual genesis blocks for Artifact Virtual and AVA
@@ -176,6 +588,7 @@ impl AvaBlockchain {
                 Rule::GenesisReferenceRequired,
                 Rule::ConstitutionalAmendment,
             ],
+            signing_keys: Vec::new(),
         };        let genesis_transaction_av = Transaction {
             id: "genesis_artifact_virtual_intelligence".to_string(),
             transaction_type: TransactionType::GenesisCreation,
@@ -185,8 +598,11 @@ impl AvaBlockchain {
             timestamp: Utc::now(),
             signature: "genesis_signature_avi".to_string(),
             constitutional_validation: true,
-        };
+            governance_authorization: None,
+            authenticator: None,
+            identity_proof: None,        };
 
+        let av_transactions_root = transactions_merkle_root(std::slice::from_ref(&genesis_transaction_av));
         let genesis_block_av = Block {
             index: 0,
             timestamp: Utc::now(),
@@ -199,9 +615,13 @@ impl AvaBlockchain {
                     Rule::ArtifactVirtualIntelligenceApproval,
                 ],
                 governance_actions: Vec::new(),
+                epoch_proof: None,
+                aborted_transaction_ids: Vec::new(),
             },
             nonce: 0,
             difficulty: 1,
+            signatures: Vec::new(),
+            transactions_root: av_transactions_root,
         };        // Genesis Block 1: AVA
         let ava_metadata = EntityMetadata {
             name: "AVA".to_string(),
@@ -233,6 +653,7 @@ impl AvaBlockchain {
                 Rule::VotingQuorum(0.67),
                 Rule::ConsensusRequired(vec!["ava-core".to_string(), "memory-core".to_string()]),
             ],
+            signing_keys: Vec::new(),
         };        let genesis_transaction_ava = Transaction {
             id: "genesis_ava".to_string(),
             transaction_type: TransactionType::GenesisCreation,
@@ -242,8 +663,11 @@ impl AvaBlockchain {
             timestamp: Utc::now(),
             signature: "genesis_signature_ava".to_string(),
             constitutional_validation: true,
-        };
+            governance_authorization: None,
+            authenticator: None,
+            identity_proof: None,        };
 
+        let ava_transactions_root = transactions_merkle_root(std::slice::from_ref(&genesis_transaction_ava));
         let genesis_block_ava = Block {
             index: 1,
             timestamp: Utc::now(),
@@ -257,19 +681,23 @@ impl AvaBlockchain {
                     Rule::ConsensusRequired(vec!["ava-core".to_string()]),
                 ],
                 governance_actions: Vec::new(),
+                epoch_proof: None,
+                aborted_transaction_ids: Vec::new(),
             },
             nonce: 0,
             difficulty: 1,
+            signatures: Vec::new(),
+            transactions_root: ava_transactions_root,
         };
 
-        // Mine and add blocks
+        // Seal and add blocks
         let mut av_block = genesis_block_av;
-        av_block.hash = self.mine_block(&mut av_block);
+        self.engine.seal_block(&mut av_block)?;
         self.chain.push(av_block.clone());
 
         let mut ava_block = genesis_block_ava;
         ava_block.previous_hash = av_block.hash.clone();
-        ava_block.hash = self.mine_block(&mut ava_block);
+        self.engine.seal_block(&mut ava_block)?;
         self.chain.push(ava_block);        // Register entities
         self.entities.insert("Artifact Virtual Intelligence".to_string(), artifact_virtual_metadata);
         self.entities.insert("AVA".to_string(), ava_metadata);
@@ -278,33 +706,10 @@ impl AvaBlockchain {
         Ok(())
     }
 
-    /// Mine a block with proof of work
-    pub fn mine_block(&self, block: &mut Block) -> String {
-        let target = "0".repeat(block.difficulty as usize);
-        
-        loop {
-            let hash = self.calculate_hash(block);
-            if hash.starts_with(&target) {
-                return hash;
-            }
-            block.nonce += 1;
-        }
-    }
-
-    /// Calculate block hash
+    /// Calculate block hash (delegates to the engine-agnostic `hash_block`,
+    /// kept as a method for callers that already hold an `AvaBlockchain`)
     pub fn calculate_hash(&self, block: &Block) -> String {
-        let mut hasher = Sha256::new();
-        let data = format!(
-            "{}{}{}{}{}{}",
-            block.index,
-            block.timestamp.timestamp(),
-            block.previous_hash,
-            serde_json::to_string(&block.data).unwrap_or_default(),
-            block.nonce,
-            block.difficulty
-        );
-        hasher.update(data.as_bytes());
-        format!("{:x}", hasher.finalize())
+        hash_block(block)
     }
 
     /// Add a new transaction to pending pool
@@ -313,6 +718,8 @@ impl AvaBlockchain {
             return Err("Genesis blocks must be created first".to_string());
         }
 
+        self.verify_governance_authorization(&transaction)?;
+
         // Validate transaction based on constitutional rules
         if self.validate_transaction(&transaction)? {
             self.pending_transactions.push(transaction);
@@ -322,6 +729,68 @@ impl AvaBlockchain {
         }
     }
 
+    /// Verify the aggregated Schnorr `governance_authorization` a
+    /// transaction carries, for `transaction_type`s that
+    /// `requires_governance_authorization` flags. Every signer must belong
+    /// to `governance_council`, the number of distinct signers must meet
+    /// `governance_threshold`, and the signers' public keys are
+    /// MuSig-aggregated and the signature is checked against the transaction
+    /// body, so a forged or partial-quorum authorization is rejected before
+    /// the transaction ever reaches the mempool.
+    fn verify_governance_authorization(&self, transaction: &Transaction) -> Result<(), String> {
+        if !requires_governance_authorization(&transaction.transaction_type) {
+            return Ok(());
+        }
+
+        let authorization = transaction.governance_authorization.as_ref().ok_or_else(|| {
+            format!(
+                "entity {} must submit a governance-council-signed authorization for this transaction",
+                transaction.sender
+            )
+        })?;
+
+        if authorization.signers.is_empty() {
+            return Err(format!(
+                "entity {} submitted a governance authorization with no signers",
+                transaction.sender
+            ));
+        }
+
+        let distinct_signers: HashSet<[u8; 32]> = authorization.signers.iter().copied().collect();
+        if distinct_signers.len() < self.governance_threshold {
+            return Err(format!(
+                "entity {} submitted a governance authorization with {} distinct signer(s), below the required threshold of {}",
+                transaction.sender,
+                distinct_signers.len(),
+                self.governance_threshold
+            ));
+        }
+
+        let mut signer_keys: Vec<GovernancePublicKey> = Vec::with_capacity(authorization.signers.len());
+        for signer in &authorization.signers {
+            if !self.governance_council.contains(signer) {
+                return Err(format!(
+                    "entity {} was co-signed by a key outside the governance council",
+                    transaction.sender
+                ));
+            }
+            let point = CompressedRistretto(*signer).decompress().ok_or_else(|| {
+                format!("entity {} authorization has an invalid signer key", transaction.sender)
+            })?;
+            signer_keys.push(point);
+        }
+
+        let aggregated_key = schnorr::aggregate_public_keys(&signer_keys);
+        let signature = SchnorrSignature {
+            r: CompressedRistretto(authorization.r),
+            s: Scalar::from_bytes_mod_order(authorization.s),
+        };
+        let message = governance_signing_payload(transaction);
+
+        SchnorrVerifier::verify(&signature, &aggregated_key, &message)
+            .map_err(|e| format!("entity {} governance authorization rejected: {}", transaction.sender, e))
+    }
+
     /// Validate transaction against constitutional rules
     pub fn validate_transaction(&self, transaction: &Transaction) -> Result<bool, String> {
         // Basic validation
@@ -343,6 +812,61 @@ impl AvaBlockchain {
                             return Err("Voting quorum not met".to_string());
                         }
                     },
+                    Rule::RequiresSignature(expected_public_key_hex) => {
+                        if !transaction.verified_signer_keys().contains(expected_public_key_hex) {
+                            return Err(format!(
+                                "transaction is not signed by the required key {}",
+                                expected_public_key_hex
+                            ));
+                        }
+                    },
+                    Rule::ConsensusRequired(entities) => {
+                        let signer_keys = transaction.verified_signer_keys();
+                        match &transaction.authenticator {
+                            Some(TransactionAuthenticator::MultiEd25519 { .. }) => {}
+                            _ => {
+                                return Err(
+                                    "consensus-required transaction must carry a multisig authenticator".to_string(),
+                                )
+                            }
+                        };
+                        // The rule -- not the sender's self-reported
+                        // `threshold` -- decides how many of `entities` must
+                        // co-sign: every named entity is required, so a
+                        // sender can't defeat this by claiming a low
+                        // threshold on their own `MultiEd25519` authenticator.
+                        let required = entities.len();
+                        let covered = entities
+                            .iter()
+                            .filter_map(|entity_name| self.entities.get(entity_name))
+                            .filter(|entity| entity.signing_keys.iter().any(|key| signer_keys.contains(key)))
+                            .count();
+                        if covered < required {
+                            return Err(format!(
+                                "only {}/{} required entities co-signed",
+                                covered,
+                                required
+                            ));
+                        }
+                    },
+                    Rule::AIValidation(expected_commitment_hex) => {
+                        if matches!(transaction.transaction_type, TransactionType::EntityRegistration) {
+                            let verifier = self.identity_verifier.as_deref().ok_or_else(|| {
+                                "entity registration requires an AIValidation zk identity proof, \
+                                 but no identity verifier is configured"
+                                    .to_string()
+                            })?;
+                            let proof = transaction.identity_proof.as_deref().ok_or_else(|| {
+                                "entity registration did not carry a zk identity proof".to_string()
+                            })?;
+                            if !verifier.verify(expected_commitment_hex, proof) {
+                                return Err(format!(
+                                    "zk identity proof did not verify against commitment {}",
+                                    expected_commitment_hex
+                                ));
+                            }
+                        }
+                    },
                     _ => {} // Other rules would be implemented here
                 }
             }
@@ -360,55 +884,138 @@ impl AvaBlockchain {
         }
     }
 
-    /// Create a new block with pending transactions
-    pub fn create_block(&mut self) -> Result<Block, String> {
+    /// Create a new block with pending transactions, re-validating each one
+    /// immediately before inclusion rather than trusting the state it had
+    /// when queued: a transaction can go stale between `add_transaction`
+    /// and mining (e.g. a parent approval it relied on gets revoked).
+    /// Transactions that fail this final check are dropped and their ids
+    /// recorded in `BlockData::aborted_transaction_ids` instead of being
+    /// silently committed. If none passes, no block is created: an error is
+    /// returned and the dropped transactions' ids are not recorded anywhere.
+    /// `rng` is threaded through for any nondeterministic
+    /// rule checks `validate_transaction` grows in the future; today's rule
+    /// set is fully deterministic and does not use it.
+    pub fn create_block(&mut self, rng: &mut impl Rng) -> Result<Block, String> {
+        let _ = rng;
         if self.pending_transactions.is_empty() {
             return Err("No pending transactions".to_string());
         }
 
         let previous_block = self.chain.last()
             .ok_or("No previous block found")?;
+        let previous_index = previous_block.index;
+        let previous_hash = previous_block.hash.clone();
+        let network_now = self.clock.network_time(Utc::now());
+        let difficulty = self.engine.expected_difficulty(previous_block, network_now);
 
+        let pending = std::mem::take(&mut self.pending_transactions);
+        let mut included = Vec::with_capacity(pending.len());
+        let mut aborted_transaction_ids = Vec::new();
+        for transaction in pending {
+            match self.validate_transaction(&transaction) {
+                Ok(true) => included.push(transaction),
+                Ok(false) | Err(_) => aborted_transaction_ids.push(transaction.id.clone()),
+            }
+        }
+
+        if included.is_empty() {
+            return Err("No pending transactions passed verification".to_string());
+        }
+
+        let transactions_root = transactions_merkle_root(&included);
         let mut new_block = Block {
-            index: previous_block.index + 1,
-            timestamp: Utc::now(),
-            previous_hash: previous_block.hash.clone(),
+            index: previous_index + 1,
+            timestamp: network_now,
+            previous_hash,
             hash: String::new(),
             data: BlockData {
-                transactions: self.pending_transactions.clone(),
+                transactions: included,
                 entity_metadata: None,
                 constitutional_rules: Vec::new(),
                 governance_actions: Vec::new(),
+                epoch_proof: None,
+                aborted_transaction_ids,
             },
             nonce: 0,
-            difficulty: 2, // Increase difficulty after genesis
+            difficulty,
+            signatures: Vec::new(),
+            transactions_root,
         };
 
-        new_block.hash = self.mine_block(&mut new_block);
+        self.engine.seal_block(&mut new_block)?;
         self.chain.push(new_block.clone());
-        self.pending_transactions.clear();
+        self.publish_block_events(&new_block);
 
         Ok(new_block)
     }
 
-    /// Validate the entire blockchain
-    pub fn is_chain_valid(&self) -> bool {
-        for i in 1..self.chain.len() {
-            let current_block = &self.chain[i];
-            let previous_block = &self.chain[i - 1];
-
-            // Verify hash
-            let calculated_hash = self.calculate_hash(current_block);
-            if current_block.hash != calculated_hash {
-                return false;
-            }
+    /// Publish `BlockAdded`, one `TransactionIncluded`/`EntityRegistered`
+    /// per included transaction, and one `GovernanceActionStatusChanged`
+    /// per recorded governance action, so subscribers see the same chain
+    /// activity `create_block` just committed.
+    fn publish_block_events(&self, block: &Block) {
+        self.events.publish(ChainEvent::BlockAdded {
+            index: block.index,
+            hash: block.hash.clone(),
+        });
 
-            // Verify chain linkage
-            if current_block.previous_hash != previous_block.hash {
-                return false;
+        for transaction in &block.data.transactions {
+            if matches!(transaction.transaction_type, TransactionType::EntityRegistration) {
+                if let Some(entity_id) = &transaction.recipient {
+                    self.events.publish(ChainEvent::EntityRegistered {
+                        entity_id: entity_id.clone(),
+                    });
+                }
             }
+            self.events.publish(ChainEvent::TransactionIncluded {
+                transaction: transaction.clone(),
+            });
+        }
+
+        for action in &block.data.governance_actions {
+            self.events.publish(ChainEvent::GovernanceActionStatusChanged {
+                action_id: action.action_id.clone(),
+                action_type: action.action_type,
+                status: action.status.clone(),
+            });
         }
-        true
+    }
+
+    /// Validate the entire blockchain by asking the consensus engine to
+    /// re-verify every block's seal against its parent. Each (block,
+    /// parent) pair is independent of every other, so the re-verification
+    /// is spread across `block_queue::worker_count()` threads instead of
+    /// stalling the caller on one core for a long chain.
+    pub fn is_chain_valid(&self) -> bool {
+        if self.chain.len() < 2 {
+            return true;
+        }
+
+        let workers = crate::block_queue::worker_count().max(1);
+        let all_valid = std::sync::atomic::AtomicBool::new(true);
+
+        std::thread::scope(|scope| {
+            for worker in 0..workers {
+                let all_valid = &all_valid;
+                let chain = &self.chain;
+                let engine = &self.engine;
+                scope.spawn(move || {
+                    let mut i = 1 + worker;
+                    while i < chain.len() {
+                        if !all_valid.load(std::sync::atomic::Ordering::Relaxed) {
+                            return;
+                        }
+                        if engine.verify_seal(&chain[i], &chain[i - 1]).is_err() {
+                            all_valid.store(false, std::sync::atomic::Ordering::Relaxed);
+                            return;
+                        }
+                        i += workers;
+                    }
+                });
+            }
+        });
+
+        all_valid.load(std::sync::atomic::Ordering::Relaxed)
     }
 
     /// Get entity information
@@ -423,6 +1030,11 @@ impl AvaBlockchain {
             genesis_created: self.genesis_created,
             chain_valid: self.is_chain_valid(),
             latest_block_hash: self.chain.last().map(|b| b.hash.clone()),
+            aborted_transactions: self
+                .chain
+                .iter()
+                .map(|block| block.data.aborted_transaction_ids.len())
+                .sum(),
         }
     }
 
@@ -465,44 +1077,50 @@ impl AvaBlockchain {
             timestamp: chrono::Utc::now(),
             signature: "auto_generated".to_string(),
             constitutional_validation: true,
-        })
+            governance_authorization: None,
+            authenticator: None,
+            identity_proof: None,        })
     }
 
-    /// Mine pending transactions into a new block
-    pub fn mine_pending_transactions(&mut self, miner: String) -> Result<(), String> {
+    /// Mine pending transactions into a new block. Transactions are
+    /// verified in parallel on `tx_queue` before sealing so registering
+    /// many containers at once does not serialize on a single thread.
+    pub fn mine_pending_transactions(&mut self, miner: String, rng: &mut impl Rng) -> Result<(), String> {
         if self.pending_transactions.is_empty() {
             return Err("No pending transactions to mine".to_string());
         }
 
-        self.create_block()?;
+        for transaction in self.pending_transactions.drain(..) {
+            self.tx_queue.push(transaction);
+        }
+        self.tx_queue.wait_until_empty();
+        self.pending_transactions = self.tx_queue.drain_verified();
+
+        if self.pending_transactions.is_empty() {
+            return Err("No pending transactions passed verification".to_string());
+        }
+
+        self.create_block(rng)?;
         println!("⛏️  Block mined by: {}", miner);
         Ok(())
     }
 
-    /// Save blockchain to file
-    pub fn save_to_file(&self, filename: &str) -> Result<(), String> {
-        let json = serde_json::to_string_pretty(self)
-            .map_err(|e| format!("Serialization error: {}", e))?;
-        
-        std::fs::write(filename, json)
-            .map_err(|e| format!("File write error: {}", e))?;
-        
-        Ok(())
+    /// Current state of the transaction-verification queue's three stages
+    pub fn tx_queue_info(&self) -> QueueInfo {
+        self.tx_queue.info()
     }
-}
 
-impl serde::Serialize for AvaBlockchain {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("AvaBlockchain", 4)?;
-        state.serialize_field("chain", &self.chain)?;
-        state.serialize_field("pending_transactions", &self.pending_transactions)?;
-        state.serialize_field("entities", &self.entities)?;
-        state.serialize_field("genesis_created", &self.genesis_created)?;        state.end()
+    /// Builds an inclusion proof that the transaction `tx_id` is the one
+    /// folded into `chain[block_index]`'s `transactions_root`. Returns
+    /// `None` if the block index is out of range or the block doesn't
+    /// contain a transaction with that id, so a light client can check
+    /// the result with `verify_inclusion` against the block header alone.
+    pub fn prove_transaction(&self, block_index: u64, tx_id: &str) -> Option<InclusionProof> {
+        let block = self.chain.get(block_index as usize)?;
+        let leaf_index = block.data.transactions.iter().position(|transaction| transaction.id == tx_id)?;
+        merkle::merkle_proof(&transaction_leaves(&block.data.transactions), leaf_index)
     }
+
 }
 
 /// Blockchain status information
@@ -514,6 +1132,10 @@ pub struct BlockchainStatus {
     pub genesis_created: bool,
     pub chain_valid: bool,
     pub latest_block_hash: Option<String>,
+    /// Total transactions across the chain that were pending at block-
+    /// creation time but failed final re-validation and were dropped
+    /// instead of committed (see `BlockData::aborted_transaction_ids`).
+    pub aborted_transactions: usize,
 }
 
 impl fmt::Display for BlockchainStatus {
@@ -524,13 +1146,15 @@ impl fmt::Display for BlockchainStatus {
                    - Entities: {}\n\
                    - Genesis Created: {}\n\
                    - Chain Valid: {}\n\
-                   - Latest Hash: {}",
+                   - Latest Hash: {}\n\
+                   - Aborted Transactions: {}",
                    self.total_blocks,
                    self.pending_transactions,
                    self.entities_count,
                    self.genesis_created,
                    self.chain_valid,
-                   self.latest_block_hash.as_deref().unwrap_or("None"))
+                   self.latest_block_hash.as_deref().unwrap_or("None"),
+                   self.aborted_transactions)
     }
 }
 
@@ -572,9 +1196,547 @@ mod tests {
             timestamp: Utc::now(),
             signature: "test_sig".to_string(),
             constitutional_validation: true,
-        };
-        
+            governance_authorization: None,
+            authenticator: None,
+            identity_proof: None,        };
+        // AVA's genesis ConsensusRequired rule needs ava-core and memory-core
+        // to co-sign.
+        let mut transaction = transaction;
+        co_sign_for_ava_consensus(&mut blockchain, &mut transaction, &[]);
+
         // Should succeed with constitutional validation
         assert!(blockchain.add_transaction(transaction).is_ok());
     }
+
+    /// Registers `ava-core` and `memory-core` with fresh signing keys and
+    /// co-signs `transaction` with them, plus `extra_keys`, as one
+    /// `MultiEd25519`, so a transaction from AVA meets its genesis
+    /// `ConsensusRequired` rule.
+    fn co_sign_for_ava_consensus(
+        blockchain: &mut AvaBlockchain,
+        transaction: &mut Transaction,
+        extra_keys: &[&ed25519_dalek::SigningKey],
+    ) {
+        let mut keys: Vec<ed25519_dalek::SigningKey> = extra_keys.iter().map(|key| (*key).clone()).collect();
+        for name in ["ava-core", "memory-core"] {
+            let key = ed25519_dalek::SigningKey::generate(&mut rand::rngs::OsRng);
+            blockchain.entities.insert(
+                name.to_string(),
+                EntityMetadata {
+                    name: name.to_string(),
+                    entity_type: EntityType::ContainerService,
+                    genesis_timestamp: Utc::now(),
+                    description: String::new(),
+                    maintainers: Vec::new(),
+                    capabilities: Vec::new(),
+                    vision_statement: String::new(),
+                    parent_entity: Some("AVA".to_string()),
+                    child_entities: Vec::new(),
+                    constitutional_constraints: Vec::new(),
+                    signing_keys: vec![hex::encode(key.verifying_key().to_bytes())],
+                },
+            );
+            keys.push(key);
+        }
+        let message = governance_signing_payload(transaction);
+        transaction.authenticator = Some(TransactionAuthenticator::MultiEd25519 {
+            public_keys: keys.iter().map(|key| key.verifying_key().to_bytes()).collect(),
+            signatures: keys.iter().map(|key| ed25519_dalek::Signer::sign(key, &message).to_bytes()).collect(),
+            threshold: keys.len(),
+        });
+    }
+
+    fn unsigned_module_deployment() -> Transaction {
+        Transaction {
+            id: "register_ava-core".to_string(),
+            transaction_type: TransactionType::ModuleDeployment,
+            sender: "AVA".to_string(),
+            recipient: Some("ava-core".to_string()),
+            data: serde_json::json!({"container_name": "ava-core"}),
+            timestamp: Utc::now(),
+            signature: "sig_ava-core".to_string(),
+            constitutional_validation: true,
+            governance_authorization: None,
+            authenticator: None,
+            identity_proof: None,        }
+    }
+
+    #[test]
+    fn module_deployment_without_authorization_is_rejected() {
+        let mut blockchain = AvaBlockchain::new();
+        blockchain.create_dual_genesis().unwrap();
+
+        let err = blockchain.add_transaction(unsigned_module_deployment()).unwrap_err();
+        assert!(err.contains("AVA"));
+    }
+
+    #[test]
+    fn module_deployment_with_valid_council_signature_is_admitted() {
+        let mut blockchain = AvaBlockchain::new();
+        blockchain.create_dual_genesis().unwrap();
+
+        let secret = Scalar::random(&mut rand::rngs::OsRng);
+        let public_key = RISTRETTO_BASEPOINT_POINT * secret;
+        blockchain.set_governance_council(vec![*public_key.compress().as_bytes()]);
+
+        let mut transaction = unsigned_module_deployment();
+        let message = governance_signing_payload(&transaction);
+        let signature = schnorr::sign_aggregated(&[(secret, public_key)], &message);
+        transaction.governance_authorization = Some(GovernanceAuthorization {
+            signers: vec![*public_key.compress().as_bytes()],
+            r: *signature.r.as_bytes(),
+            s: signature.s.to_bytes(),
+        });
+        co_sign_for_ava_consensus(&mut blockchain, &mut transaction, &[]);
+
+        assert!(blockchain.add_transaction(transaction).is_ok());
+    }
+
+    #[test]
+    fn module_deployment_signed_by_a_non_council_key_is_rejected() {
+        let mut blockchain = AvaBlockchain::new();
+        blockchain.create_dual_genesis().unwrap();
+
+        let council_secret = Scalar::random(&mut rand::rngs::OsRng);
+        let council_key = RISTRETTO_BASEPOINT_POINT * council_secret;
+        blockchain.set_governance_council(vec![*council_key.compress().as_bytes()]);
+
+        let outsider_secret = Scalar::random(&mut rand::rngs::OsRng);
+        let outsider_key = RISTRETTO_BASEPOINT_POINT * outsider_secret;
+
+        let mut transaction = unsigned_module_deployment();
+        let message = governance_signing_payload(&transaction);
+        let signature = schnorr::sign_aggregated(&[(outsider_secret, outsider_key)], &message);
+        transaction.governance_authorization = Some(GovernanceAuthorization {
+            signers: vec![*outsider_key.compress().as_bytes()],
+            r: *signature.r.as_bytes(),
+            s: signature.s.to_bytes(),
+        });
+
+        let err = blockchain.add_transaction(transaction).unwrap_err();
+        assert!(err.contains("outside the governance council"));
+    }
+
+    #[test]
+    fn module_deployment_below_governance_threshold_is_rejected() {
+        let mut blockchain = AvaBlockchain::new();
+        blockchain.create_dual_genesis().unwrap();
+
+        let secret_a = Scalar::random(&mut rand::rngs::OsRng);
+        let key_a = RISTRETTO_BASEPOINT_POINT * secret_a;
+        let secret_b = Scalar::random(&mut rand::rngs::OsRng);
+        let key_b = RISTRETTO_BASEPOINT_POINT * secret_b;
+        blockchain.set_governance_council(vec![
+            *key_a.compress().as_bytes(),
+            *key_b.compress().as_bytes(),
+        ]);
+        blockchain.set_governance_threshold(2);
+
+        let mut transaction = unsigned_module_deployment();
+        let message = governance_signing_payload(&transaction);
+        // Only one of the two required council members signs.
+        let signature = schnorr::sign_aggregated(&[(secret_a, key_a)], &message);
+        transaction.governance_authorization = Some(GovernanceAuthorization {
+            signers: vec![*key_a.compress().as_bytes()],
+            r: *signature.r.as_bytes(),
+            s: signature.s.to_bytes(),
+        });
+
+        let err = blockchain.add_transaction(transaction).unwrap_err();
+        assert!(err.contains("below the required threshold"));
+    }
+
+    #[test]
+    fn module_deployment_meeting_governance_threshold_is_admitted() {
+        let mut blockchain = AvaBlockchain::new();
+        blockchain.create_dual_genesis().unwrap();
+
+        let secret_a = Scalar::random(&mut rand::rngs::OsRng);
+        let key_a = RISTRETTO_BASEPOINT_POINT * secret_a;
+        let secret_b = Scalar::random(&mut rand::rngs::OsRng);
+        let key_b = RISTRETTO_BASEPOINT_POINT * secret_b;
+        blockchain.set_governance_council(vec![
+            *key_a.compress().as_bytes(),
+            *key_b.compress().as_bytes(),
+        ]);
+        blockchain.set_governance_threshold(2);
+
+        let mut transaction = unsigned_module_deployment();
+        let message = governance_signing_payload(&transaction);
+        let signature =
+            schnorr::sign_aggregated(&[(secret_a, key_a), (secret_b, key_b)], &message);
+        transaction.governance_authorization = Some(GovernanceAuthorization {
+            signers: vec![*key_a.compress().as_bytes(), *key_b.compress().as_bytes()],
+            r: *signature.r.as_bytes(),
+            s: signature.s.to_bytes(),
+        });
+        co_sign_for_ava_consensus(&mut blockchain, &mut transaction, &[]);
+
+        assert!(blockchain.add_transaction(transaction).is_ok());
+    }
+
+    #[test]
+    fn test_import_queue_admits_verified_blocks_in_order() {
+        let mut blockchain = AvaBlockchain::new();
+        blockchain.create_dual_genesis().unwrap();
+
+        let tip = blockchain.chain.last().unwrap().clone();
+        let timestamp = Utc::now();
+        let mut next_block = Block {
+            index: tip.index + 1,
+            timestamp,
+            previous_hash: tip.hash.clone(),
+            hash: String::new(),
+            data: BlockData {
+                transactions: Vec::new(),
+                entity_metadata: None,
+                constitutional_rules: Vec::new(),
+                governance_actions: Vec::new(),
+                epoch_proof: None,
+                aborted_transaction_ids: Vec::new(),
+            },
+            nonce: 0,
+            difficulty: blockchain.engine.expected_difficulty(&tip, timestamp),
+            signatures: Vec::new(),
+            transactions_root: transactions_merkle_root(&[]),
+        };
+        blockchain.engine.seal_block(&mut next_block).unwrap();
+
+        let chain_len_before = blockchain.chain.len();
+        blockchain.queue_block_for_import(next_block.clone());
+        blockchain.import_queue.wait_until_empty();
+
+        let imported = blockchain.import_queued_blocks().unwrap();
+        assert_eq!(imported, 1);
+        assert_eq!(blockchain.chain.len(), chain_len_before + 1);
+        assert_eq!(blockchain.chain.last().unwrap().hash, next_block.hash);
+    }
+
+    #[test]
+    fn import_rejects_a_block_whose_timestamp_drifts_past_the_bound() {
+        let mut blockchain = AvaBlockchain::new();
+        blockchain.create_dual_genesis().unwrap();
+        blockchain.max_clock_drift = Duration::seconds(5);
+
+        let tip = blockchain.chain.last().unwrap().clone();
+        let mut drifted_block = Block {
+            index: tip.index + 1,
+            timestamp: Utc::now() + Duration::minutes(10),
+            previous_hash: tip.hash.clone(),
+            hash: String::new(),
+            data: BlockData {
+                transactions: Vec::new(),
+                entity_metadata: None,
+                constitutional_rules: Vec::new(),
+                governance_actions: Vec::new(),
+                epoch_proof: None,
+                aborted_transaction_ids: Vec::new(),
+            },
+            nonce: 0,
+            difficulty: tip.difficulty,
+            signatures: Vec::new(),
+            transactions_root: transactions_merkle_root(&[]),
+        };
+        blockchain.engine.seal_block(&mut drifted_block).unwrap();
+
+        let chain_len_before = blockchain.chain.len();
+        blockchain.queue_block_for_import(drifted_block);
+        blockchain.import_queue.wait_until_empty();
+
+        assert!(blockchain.import_queued_blocks().is_err());
+        assert_eq!(blockchain.chain.len(), chain_len_before);
+    }
+
+    #[test]
+    fn signing_and_verifying_a_transaction_round_trips() {
+        let key = ed25519_dalek::SigningKey::generate(&mut rand::rngs::OsRng);
+        let mut transaction = unsigned_module_deployment();
+        transaction.sign(&key);
+        assert!(transaction.verify_signature());
+    }
+
+    #[test]
+    fn requires_signature_rule_accepts_the_expected_key_and_rejects_others() {
+        let mut blockchain = AvaBlockchain::new();
+        blockchain.create_dual_genesis().unwrap();
+
+        let key = ed25519_dalek::SigningKey::generate(&mut rand::rngs::OsRng);
+        let public_key_hex = hex::encode(key.verifying_key().to_bytes());
+        blockchain
+            .entities
+            .get_mut("AVA")
+            .unwrap()
+            .constitutional_constraints
+            .push(Rule::RequiresSignature(public_key_hex));
+
+        // Each transaction is also co-signed by ava-core and memory-core for
+        // AVA's genesis ConsensusRequired rule.
+        let mut signed = unsigned_module_deployment();
+        co_sign_for_ava_consensus(&mut blockchain, &mut signed, &[&key]);
+        assert!(blockchain.validate_transaction(&signed).unwrap());
+
+        let other_key = ed25519_dalek::SigningKey::generate(&mut rand::rngs::OsRng);
+        let mut wrongly_signed = unsigned_module_deployment();
+        co_sign_for_ava_consensus(&mut blockchain, &mut wrongly_signed, &[&other_key]);
+        assert!(blockchain.validate_transaction(&wrongly_signed).is_err());
+    }
+
+    #[test]
+    fn ai_validation_rule_requires_a_configured_verifier_and_a_matching_proof() {
+        let mut blockchain = AvaBlockchain::new();
+        blockchain.create_dual_genesis().unwrap();
+
+        let commitment_hex = "deadbeef".to_string();
+        blockchain
+            .entities
+            .get_mut("AVA")
+            .unwrap()
+            .constitutional_constraints
+            .push(Rule::AIValidation(commitment_hex));
+
+        let mut registration = Transaction {
+            id: "register_new_module".to_string(),
+            transaction_type: TransactionType::EntityRegistration,
+            sender: "AVA".to_string(),
+            recipient: Some("new-module".to_string()),
+            data: serde_json::json!({}),
+            timestamp: Utc::now(),
+            signature: "sig".to_string(),
+            constitutional_validation: true,
+            governance_authorization: None,
+            authenticator: None,
+            identity_proof: None,
+        };
+        // Meets AVA's genesis ConsensusRequired rule, so AIValidation alone
+        // decides the outcomes below; `identity_proof` is outside the signed
+        // payload, so attaching one later keeps the co-signature valid.
+        co_sign_for_ava_consensus(&mut blockchain, &mut registration, &[]);
+
+        // No verifier configured: AIValidation rejects rather than passing silently.
+        assert!(blockchain.validate_transaction(&registration).is_err());
+
+        struct StubVerifier;
+        impl IdentityProofVerifier for StubVerifier {
+            fn verify(&self, commitment: &str, proof: &[u8]) -> bool {
+                commitment == "deadbeef" && proof == b"valid-proof"
+            }
+        }
+        blockchain.set_identity_verifier(Box::new(StubVerifier));
+
+        // Verifier configured but no proof attached yet.
+        assert!(blockchain.validate_transaction(&registration).is_err());
+
+        registration.identity_proof = Some(b"wrong-proof".to_vec());
+        assert!(blockchain.validate_transaction(&registration).is_err());
+
+        registration.identity_proof = Some(b"valid-proof".to_vec());
+        assert!(blockchain.validate_transaction(&registration).unwrap());
+    }
+
+    #[test]
+    fn consensus_required_rule_checks_entity_signing_keys_against_threshold() {
+        let mut blockchain = AvaBlockchain::new();
+        blockchain.create_dual_genesis().unwrap();
+
+        let ava_core_key = ed25519_dalek::SigningKey::generate(&mut rand::rngs::OsRng);
+        let memory_core_key = ed25519_dalek::SigningKey::generate(&mut rand::rngs::OsRng);
+        blockchain.entities.insert(
+            "ava-core".to_string(),
+            EntityMetadata {
+                name: "ava-core".to_string(),
+                entity_type: EntityType::ContainerService,
+                genesis_timestamp: Utc::now(),
+                description: String::new(),
+                maintainers: Vec::new(),
+                capabilities: Vec::new(),
+                vision_statement: String::new(),
+                parent_entity: Some("AVA".to_string()),
+                child_entities: Vec::new(),
+                constitutional_constraints: Vec::new(),
+                signing_keys: vec![hex::encode(ava_core_key.verifying_key().to_bytes())],
+            },
+        );
+        blockchain.entities.insert(
+            "memory-core".to_string(),
+            EntityMetadata {
+                name: "memory-core".to_string(),
+                entity_type: EntityType::ContainerService,
+                genesis_timestamp: Utc::now(),
+                description: String::new(),
+                maintainers: Vec::new(),
+                capabilities: Vec::new(),
+                vision_statement: String::new(),
+                parent_entity: Some("AVA".to_string()),
+                child_entities: Vec::new(),
+                constitutional_constraints: Vec::new(),
+                signing_keys: vec![hex::encode(memory_core_key.verifying_key().to_bytes())],
+            },
+        );
+        blockchain
+            .entities
+            .get_mut("AVA")
+            .unwrap()
+            .constitutional_constraints
+            .push(Rule::ConsensusRequired(vec![
+                "ava-core".to_string(),
+                "memory-core".to_string(),
+            ]));
+
+        let mut transaction = unsigned_module_deployment();
+        let message = governance_signing_payload(&transaction);
+        transaction.authenticator = Some(TransactionAuthenticator::MultiEd25519 {
+            public_keys: vec![ava_core_key.verifying_key().to_bytes()],
+            signatures: vec![ed25519_dalek::Signer::sign(&ava_core_key, &message).to_bytes()],
+            threshold: 2,
+        });
+        assert!(blockchain.validate_transaction(&transaction).is_err());
+
+        transaction.authenticator = Some(TransactionAuthenticator::MultiEd25519 {
+            public_keys: vec![
+                ava_core_key.verifying_key().to_bytes(),
+                memory_core_key.verifying_key().to_bytes(),
+            ],
+            signatures: vec![
+                ed25519_dalek::Signer::sign(&ava_core_key, &message).to_bytes(),
+                ed25519_dalek::Signer::sign(&memory_core_key, &message).to_bytes(),
+            ],
+            threshold: 2,
+        });
+        assert!(blockchain.validate_transaction(&transaction).unwrap());
+    }
+
+    #[test]
+    fn consensus_required_rule_ignores_an_attacker_chosen_low_threshold() {
+        let mut blockchain = AvaBlockchain::new();
+        blockchain.create_dual_genesis().unwrap();
+
+        let ava_core_key = ed25519_dalek::SigningKey::generate(&mut rand::rngs::OsRng);
+        let memory_core_key = ed25519_dalek::SigningKey::generate(&mut rand::rngs::OsRng);
+        blockchain.entities.insert(
+            "ava-core".to_string(),
+            EntityMetadata {
+                name: "ava-core".to_string(),
+                entity_type: EntityType::ContainerService,
+                genesis_timestamp: Utc::now(),
+                description: String::new(),
+                maintainers: Vec::new(),
+                capabilities: Vec::new(),
+                vision_statement: String::new(),
+                parent_entity: Some("AVA".to_string()),
+                child_entities: Vec::new(),
+                constitutional_constraints: Vec::new(),
+                signing_keys: vec![hex::encode(ava_core_key.verifying_key().to_bytes())],
+            },
+        );
+        blockchain.entities.insert(
+            "memory-core".to_string(),
+            EntityMetadata {
+                name: "memory-core".to_string(),
+                entity_type: EntityType::ContainerService,
+                genesis_timestamp: Utc::now(),
+                description: String::new(),
+                maintainers: Vec::new(),
+                capabilities: Vec::new(),
+                vision_statement: String::new(),
+                parent_entity: Some("AVA".to_string()),
+                child_entities: Vec::new(),
+                constitutional_constraints: Vec::new(),
+                signing_keys: vec![hex::encode(memory_core_key.verifying_key().to_bytes())],
+            },
+        );
+        blockchain
+            .entities
+            .get_mut("AVA")
+            .unwrap()
+            .constitutional_constraints
+            .push(Rule::ConsensusRequired(vec![
+                "ava-core".to_string(),
+                "memory-core".to_string(),
+            ]));
+
+        // The sender claims a `threshold` of 0 on their own authenticator --
+        // and even supplies zero signatures at all -- trying to make
+        // `covered < threshold` never fire. The rule requires every named
+        // entity regardless of what `threshold` the sender self-reports.
+        let mut transaction = unsigned_module_deployment();
+        transaction.authenticator = Some(TransactionAuthenticator::MultiEd25519 {
+            public_keys: Vec::new(),
+            signatures: Vec::new(),
+            threshold: 0,
+        });
+        assert!(blockchain.validate_transaction(&transaction).is_err());
+
+        // A single real co-signature with a claimed `threshold: 1` still
+        // doesn't satisfy the rule -- both entities are required.
+        let message = governance_signing_payload(&transaction);
+        transaction.authenticator = Some(TransactionAuthenticator::MultiEd25519 {
+            public_keys: vec![ava_core_key.verifying_key().to_bytes()],
+            signatures: vec![ed25519_dalek::Signer::sign(&ava_core_key, &message).to_bytes()],
+            threshold: 1,
+        });
+        assert!(blockchain.validate_transaction(&transaction).is_err());
+    }
+
+    #[test]
+    fn prove_transaction_round_trips_through_verify_inclusion() {
+        let mut blockchain = AvaBlockchain::new();
+        blockchain.create_dual_genesis().unwrap();
+
+        let genesis_block = &blockchain.chain[0];
+        let tx_id = &genesis_block.data.transactions[0].id;
+        let tx_leaf = transaction_leaf(&genesis_block.data.transactions[0]);
+        let root: [u8; 32] = hex::decode(&genesis_block.transactions_root)
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        let proof = blockchain.prove_transaction(0, tx_id).unwrap();
+        assert!(merkle::verify_inclusion(&root, tx_leaf, &proof));
+    }
+
+    #[test]
+    fn prove_transaction_is_none_for_an_unknown_id() {
+        let mut blockchain = AvaBlockchain::new();
+        blockchain.create_dual_genesis().unwrap();
+
+        assert!(blockchain.prove_transaction(0, "not-a-real-tx").is_none());
+        assert!(blockchain.prove_transaction(99, "genesis_artifact_virtual_intelligence").is_none());
+    }
+
+    #[test]
+    fn create_block_drops_transactions_that_fail_revalidation_and_keeps_the_rest() {
+        let mut blockchain = AvaBlockchain::new();
+        blockchain.create_dual_genesis().unwrap();
+
+        blockchain.pending_transactions.push(unsigned_module_deployment());
+        blockchain.pending_transactions.push(Transaction {
+            id: "tx_survives".to_string(),
+            transaction_type: TransactionType::EntityRegistration,
+            sender: "Artifact Virtual Intelligence".to_string(),
+            recipient: None,
+            data: serde_json::json!({}),
+            timestamp: Utc::now(),
+            signature: "sig".to_string(),
+            constitutional_validation: true,
+            governance_authorization: None,
+            authenticator: None,
+            identity_proof: None,        });
+
+        // A signature requirement lands on AVA after the transaction was
+        // queued but before mining -- e.g. a revoked parent approval.
+        let key = ed25519_dalek::SigningKey::generate(&mut rand::rngs::OsRng);
+        blockchain
+            .entities
+            .get_mut("AVA")
+            .unwrap()
+            .constitutional_constraints
+            .push(Rule::RequiresSignature(hex::encode(key.verifying_key().to_bytes())));
+
+        let block = blockchain.create_block(&mut rand::rngs::OsRng).unwrap();
+
+        assert_eq!(block.data.transactions.len(), 1);
+        assert_eq!(block.data.transactions[0].id, "tx_survives");
+        assert_eq!(block.data.aborted_transaction_ids, vec!["register_ava-core".to_string()]);
+        assert_eq!(blockchain.get_status().aborted_transactions, 1);
+    }
 }
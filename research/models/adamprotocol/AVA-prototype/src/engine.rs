@@ -0,0 +1,356 @@
+// Consensus Engine Abstraction for AVA Constitutional Intelligence
+// Separates block sealing/verification from the chain itself so the same
+// `AvaBlockchain` can run proof-of-work, single-authority, or BFT-style
+// quorum consensus depending on how the constitutional rules configure it.
+
+use crate::blockchain::{hash_block, Block};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Target seconds between blocks that `PowEngine::expected_difficulty`
+/// retargets against.
+const TARGET_BLOCK_SECONDS: i64 = 5;
+
+/// Abstracts how a block is sealed (produced) and verified.
+///
+/// The machine-specific state (difficulty, authorized signers, quorum
+/// fraction) lives on the concrete engine implementation; `AvaBlockchain`
+/// itself only ever talks to this trait.
+pub trait ConsensusEngine: Send + Sync {
+    /// Seal a freshly assembled block, filling in `hash` (and, for
+    /// authority/quorum engines, `signatures`).
+    fn seal_block(&self, block: &mut Block) -> Result<(), String>;
+
+    /// Verify that `block` was properly sealed given its `parent`.
+    fn verify_seal(&self, block: &Block, parent: &Block) -> Result<(), String>;
+
+    /// The set of entity/maintainer ids allowed (or required) to seal or
+    /// co-sign this block under this engine.
+    fn expected_signers(&self, block: &Block) -> Vec<String>;
+
+    /// The difficulty `create_block` should stamp on the next block given
+    /// `parent`. `now` is the caller's NTP-synced clock reading (see
+    /// `AvaBlockchain::clock`), not the local wall clock, so retargeting
+    /// can't be skewed by an unsynced node. Authority/quorum engines that
+    /// don't mine ignore this and return a constant; `PowEngine` is the
+    /// only implementation that actually retargets.
+    fn expected_difficulty(&self, parent: &Block, now: DateTime<Utc>) -> u32;
+}
+
+/// Classic SHA-256 leading-zeros proof of work. This is the engine the
+/// chain has always used; `block.difficulty` carries the per-block target.
+#[derive(Debug, Default)]
+pub struct PowEngine;
+
+impl ConsensusEngine for PowEngine {
+    fn seal_block(&self, block: &mut Block) -> Result<(), String> {
+        let target = "0".repeat(block.difficulty as usize);
+        loop {
+            let hash = hash_block(block);
+            if hash.starts_with(&target) {
+                block.hash = hash;
+                return Ok(());
+            }
+            block.nonce += 1;
+        }
+    }
+
+    fn verify_seal(&self, block: &Block, parent: &Block) -> Result<(), String> {
+        if block.previous_hash != parent.hash {
+            return Err("previous_hash does not match parent block".to_string());
+        }
+        // An imported block must also retarget correctly -- otherwise a
+        // peer could mine/import a chain that never raises its difficulty
+        // (e.g. stays at 1 forever) and satisfy the PoW target check below
+        // without ever being held to `expected_difficulty`. The dual-genesis
+        // bootstrap pair (index 0/1, created directly by `create_dual_genesis`
+        // rather than `create_block`) is seeded at a fixed difficulty and is
+        // exempt, since it never went through retargeting in the first place.
+        if parent.index > 0 {
+            let required_difficulty = self.expected_difficulty(parent, block.timestamp);
+            if block.difficulty != required_difficulty {
+                return Err(format!(
+                    "block difficulty {} does not match the expected retarget {}",
+                    block.difficulty, required_difficulty
+                ));
+            }
+        }
+        let target = "0".repeat(block.difficulty as usize);
+        if !block.hash.starts_with(&target) {
+            return Err("block hash does not satisfy the difficulty target".to_string());
+        }
+        if hash_block(block) != block.hash {
+            return Err("block hash does not match block contents".to_string());
+        }
+        Ok(())
+    }
+
+    fn expected_signers(&self, _block: &Block) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Retargets toward `TARGET_BLOCK_SECONDS` per block: raise the
+    /// difficulty if less than half that has elapsed since `parent`, lower
+    /// it (floor 1) if more than twice that has, and otherwise keep it.
+    fn expected_difficulty(&self, parent: &Block, now: DateTime<Utc>) -> u32 {
+        let elapsed = (now - parent.timestamp).num_seconds();
+        if elapsed < TARGET_BLOCK_SECONDS / 2 {
+            parent.difficulty + 1
+        } else if elapsed > TARGET_BLOCK_SECONDS * 2 {
+            parent.difficulty.saturating_sub(1).max(1)
+        } else {
+            parent.difficulty.max(1)
+        }
+    }
+}
+
+/// Single-maintainer authority sealing: a block is only valid if it
+/// carries a signature from the configured maintainer key.
+#[derive(Debug, Clone)]
+pub struct BasicAuthority {
+    pub maintainer_key: String,
+}
+
+impl BasicAuthority {
+    pub fn new(maintainer_key: impl Into<String>) -> Self {
+        BasicAuthority {
+            maintainer_key: maintainer_key.into(),
+        }
+    }
+
+    fn sign(&self, block: &Block) -> String {
+        format!("auth_sig:{}:{}", self.maintainer_key, hash_block(block))
+    }
+}
+
+impl ConsensusEngine for BasicAuthority {
+    fn seal_block(&self, block: &mut Block) -> Result<(), String> {
+        block.hash = hash_block(block);
+        block.signatures = vec![self.sign(block)];
+        Ok(())
+    }
+
+    fn verify_seal(&self, block: &Block, parent: &Block) -> Result<(), String> {
+        if block.previous_hash != parent.hash {
+            return Err("previous_hash does not match parent block".to_string());
+        }
+        if hash_block(block) != block.hash {
+            return Err("block hash does not match block contents".to_string());
+        }
+        let expected = self.sign(block);
+        if !block.signatures.contains(&expected) {
+            return Err(format!(
+                "block is not signed by authorized maintainer {}",
+                self.maintainer_key
+            ));
+        }
+        Ok(())
+    }
+
+    fn expected_signers(&self, _block: &Block) -> Vec<String> {
+        vec![self.maintainer_key.clone()]
+    }
+
+    /// Authority sealing doesn't mine, so there's no difficulty target.
+    fn expected_difficulty(&self, _parent: &Block, _now: DateTime<Utc>) -> u32 {
+        0
+    }
+}
+
+/// BFT-style quorum sealing: a block is valid once at least `quorum`
+/// fraction of the `ConsensusRequired` container set have signed it,
+/// mirroring `Rule::ConsensusRequired`/`Rule::VotingQuorum`.
+#[derive(Debug, Clone)]
+pub struct QuorumBft {
+    pub consensus_required: Vec<String>,
+    pub quorum: f32,
+}
+
+impl QuorumBft {
+    pub fn new(consensus_required: Vec<String>, quorum: f32) -> Self {
+        QuorumBft {
+            consensus_required,
+            quorum,
+        }
+    }
+
+    fn sign(&self, signer: &str, block: &Block) -> String {
+        format!("quorum_sig:{}:{}", signer, hash_block(block))
+    }
+}
+
+impl ConsensusEngine for QuorumBft {
+    /// Seals the block with the hash and co-signs on behalf of every
+    /// container in the consensus set that is simulated as always-online.
+    /// In a real deployment each container signs independently and appends
+    /// to `block.signatures`; here we seal optimistically so the chain can
+    /// still make progress in-process.
+    fn seal_block(&self, block: &mut Block) -> Result<(), String> {
+        block.hash = hash_block(block);
+        block.signatures = self
+            .consensus_required
+            .iter()
+            .map(|signer| self.sign(signer, block))
+            .collect();
+        Ok(())
+    }
+
+    fn verify_seal(&self, block: &Block, parent: &Block) -> Result<(), String> {
+        if block.previous_hash != parent.hash {
+            return Err("previous_hash does not match parent block".to_string());
+        }
+        if hash_block(block) != block.hash {
+            return Err("block hash does not match block contents".to_string());
+        }
+        if self.consensus_required.is_empty() {
+            return Err("quorum consensus set is empty".to_string());
+        }
+        let signed = self
+            .consensus_required
+            .iter()
+            .filter(|signer| block.signatures.contains(&self.sign(signer, block)))
+            .count();
+        let fraction = signed as f32 / self.consensus_required.len() as f32;
+        if fraction < self.quorum {
+            return Err(format!(
+                "only {}/{} consensus members signed ({:.2} < required {:.2})",
+                signed,
+                self.consensus_required.len(),
+                fraction,
+                self.quorum
+            ));
+        }
+        Ok(())
+    }
+
+    fn expected_signers(&self, _block: &Block) -> Vec<String> {
+        self.consensus_required.clone()
+    }
+
+    /// Quorum sealing doesn't mine, so there's no difficulty target.
+    fn expected_difficulty(&self, _parent: &Block, _now: DateTime<Utc>) -> u32 {
+        0
+    }
+}
+
+/// Declarative selector so a chain spec / genesis config can name an
+/// engine without the caller constructing a `Box<dyn ConsensusEngine>`
+/// by hand.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum EngineSelector {
+    ProofOfWork,
+    Authority { maintainer_key: String },
+    QuorumBft {
+        consensus_required: Vec<String>,
+        quorum: f32,
+    },
+}
+
+impl EngineSelector {
+    pub fn build(&self) -> Box<dyn ConsensusEngine> {
+        match self {
+            EngineSelector::ProofOfWork => Box::new(PowEngine),
+            EngineSelector::Authority { maintainer_key } => {
+                Box::new(BasicAuthority::new(maintainer_key.clone()))
+            }
+            EngineSelector::QuorumBft {
+                consensus_required,
+                quorum,
+            } => Box::new(QuorumBft::new(consensus_required.clone(), *quorum)),
+        }
+    }
+}
+
+impl Default for EngineSelector {
+    fn default() -> Self {
+        EngineSelector::ProofOfWork
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blockchain::BlockData;
+    use chrono::Utc;
+
+    fn sample_block(index: u64, previous_hash: &str, difficulty: u32) -> Block {
+        Block {
+            index,
+            timestamp: Utc::now(),
+            previous_hash: previous_hash.to_string(),
+            hash: String::new(),
+            data: BlockData {
+                transactions: Vec::new(),
+                entity_metadata: None,
+                constitutional_rules: Vec::new(),
+                governance_actions: Vec::new(),
+                epoch_proof: None,
+                aborted_transaction_ids: Vec::new(),
+            },
+            nonce: 0,
+            difficulty,
+            signatures: Vec::new(),
+            transactions_root: String::new(),
+        }
+    }
+
+    #[test]
+    fn pow_engine_seals_and_verifies() {
+        let engine = PowEngine;
+        let parent = sample_block(0, "0", 1);
+        let mut child = sample_block(1, &hash_block(&parent), 1);
+        engine.seal_block(&mut child).unwrap();
+        assert!(engine.verify_seal(&child, &parent).is_ok());
+    }
+
+    #[test]
+    fn quorum_bft_requires_threshold() {
+        let engine = QuorumBft::new(vec!["ava-core".to_string(), "memory-core".to_string()], 0.67);
+        let parent = sample_block(0, "0", 1);
+        let mut child = sample_block(1, &hash_block(&parent), 1);
+        engine.seal_block(&mut child).unwrap();
+        assert!(engine.verify_seal(&child, &parent).is_ok());
+
+        child.signatures.pop();
+        assert!(engine.verify_seal(&child, &parent).is_err());
+    }
+
+    #[test]
+    fn pow_engine_retargets_against_the_passed_in_clock_not_the_wall_clock() {
+        let engine = PowEngine;
+        let parent = sample_block(0, "0", 5);
+
+        // `now` is far in the future relative to the wall clock; if
+        // `expected_difficulty` ever fell back to `Utc::now()` internally
+        // this would retarget upward instead.
+        let synced_now = parent.timestamp + chrono::Duration::seconds(TARGET_BLOCK_SECONDS * 10);
+        assert_eq!(engine.expected_difficulty(&parent, synced_now), 4);
+
+        let synced_now = parent.timestamp + chrono::Duration::seconds(1);
+        assert_eq!(engine.expected_difficulty(&parent, synced_now), 6);
+    }
+
+    #[test]
+    fn pow_engine_verify_seal_rejects_a_block_that_never_retargeted() {
+        let engine = PowEngine;
+        // `parent.index > 0` so this isn't the dual-genesis bootstrap pair,
+        // which is exempt from retargeting.
+        let parent = sample_block(1, "0", 1);
+
+        // Mined an hour after `parent` -- `expected_difficulty` demands the
+        // difficulty drop to the floor of 1, but a dishonest peer instead
+        // kept mining at the stale difficulty of 5 and just declares it.
+        let mut stale = sample_block(2, &hash_block(&parent), 5);
+        stale.timestamp = parent.timestamp + chrono::Duration::hours(1);
+        engine.seal_block(&mut stale).unwrap();
+        assert!(engine.verify_seal(&stale, &parent).is_err());
+
+        // The honestly-retargeted difficulty for the same parent/timestamp
+        // verifies fine.
+        let mut honest = sample_block(2, &hash_block(&parent), 1);
+        honest.timestamp = stale.timestamp;
+        engine.seal_block(&mut honest).unwrap();
+        assert!(engine.verify_seal(&honest, &parent).is_ok());
+    }
+}
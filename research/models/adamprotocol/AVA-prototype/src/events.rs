@@ -0,0 +1,212 @@
+// Chain Event Subscription
+// The only way to observe chain activity used to be polling `get_status`.
+// `EventBus` lets the perception-layer/action-layer containers (and any
+// other child entity) `subscribe` with an `EventFilter` and receive a
+// `Receiver<ChainEvent>` that `create_block`/`mine_pending_transactions`
+// push typed events into as blocks are mined, instead of diffing the chain.
+
+use crate::blockchain::{GovernanceActionType, Transaction, TransactionType};
+use std::collections::HashSet;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Mutex;
+
+/// A typed occurrence on the chain, delivered to every subscriber whose
+/// `EventFilter` matches it.
+#[derive(Debug, Clone)]
+pub enum ChainEvent {
+    BlockAdded { index: u64, hash: String },
+    TransactionIncluded { transaction: Transaction },
+    GovernanceActionStatusChanged {
+        action_id: String,
+        action_type: GovernanceActionType,
+        status: crate::blockchain::ActionStatus,
+    },
+    EntityRegistered { entity_id: String },
+}
+
+/// Which events a subscriber wants. Every set field present (`Some`) is an
+/// independent predicate ANDed together; a field only narrows the event
+/// kinds it's relevant to (e.g. `transaction_types` rejects every event
+/// that isn't a `TransactionIncluded`), so `EventFilter::default()` -- no
+/// fields set -- matches everything.
+#[derive(Debug, Clone, Default)]
+pub struct EventFilter {
+    pub transaction_types: Option<HashSet<TransactionType>>,
+    /// Matches a `TransactionIncluded`'s sender/recipient or an
+    /// `EntityRegistered`'s entity id.
+    pub entities: Option<HashSet<String>>,
+    pub governance_action_types: Option<HashSet<GovernanceActionType>>,
+}
+
+impl EventFilter {
+    /// A filter with no constraints: matches every event.
+    pub fn all() -> Self {
+        EventFilter::default()
+    }
+
+    pub fn matches(&self, event: &ChainEvent) -> bool {
+        if let Some(transaction_types) = &self.transaction_types {
+            let matches = matches!(
+                event,
+                ChainEvent::TransactionIncluded { transaction }
+                    if transaction_types.contains(&transaction.transaction_type)
+            );
+            if !matches {
+                return false;
+            }
+        }
+
+        if let Some(entities) = &self.entities {
+            let matches = match event {
+                ChainEvent::TransactionIncluded { transaction } => {
+                    entities.contains(&transaction.sender)
+                        || transaction
+                            .recipient
+                            .as_ref()
+                            .is_some_and(|recipient| entities.contains(recipient))
+                }
+                ChainEvent::EntityRegistered { entity_id } => entities.contains(entity_id),
+                _ => false,
+            };
+            if !matches {
+                return false;
+            }
+        }
+
+        if let Some(governance_action_types) = &self.governance_action_types {
+            let matches = matches!(
+                event,
+                ChainEvent::GovernanceActionStatusChanged { action_type, .. }
+                    if governance_action_types.contains(action_type)
+            );
+            if !matches {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// Fan-out point between block production and subscribers: every
+/// `publish` is checked against each subscriber's filter and, on a match,
+/// sent down that subscriber's channel. A subscriber whose `Receiver` was
+/// dropped is pruned the next time an event would have matched it.
+#[derive(Default)]
+pub struct EventBus {
+    subscribers: Mutex<Vec<(EventFilter, Sender<ChainEvent>)>>,
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        EventBus::default()
+    }
+
+    /// Register a new subscriber and return the channel it will receive
+    /// matching events on.
+    pub fn subscribe(&self, filter: EventFilter) -> Receiver<ChainEvent> {
+        let (sender, receiver) = mpsc::channel();
+        self.subscribers.lock().unwrap().push((filter, sender));
+        receiver
+    }
+
+    /// Deliver `event` to every subscriber whose filter matches it.
+    pub fn publish(&self, event: ChainEvent) {
+        let mut subscribers = self.subscribers.lock().unwrap();
+        subscribers.retain(|(filter, sender)| {
+            if filter.matches(&event) {
+                sender.send(event.clone()).is_ok()
+            } else {
+                true
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blockchain::Transaction;
+    use chrono::Utc;
+
+    fn sample_transaction(transaction_type: TransactionType, sender: &str, recipient: Option<&str>) -> Transaction {
+        Transaction {
+            id: "tx".to_string(),
+            transaction_type,
+            sender: sender.to_string(),
+            recipient: recipient.map(|r| r.to_string()),
+            data: serde_json::json!({}),
+            timestamp: Utc::now(),
+            signature: "sig".to_string(),
+            constitutional_validation: true,
+            governance_authorization: None,
+            authenticator: None,
+            identity_proof: None,
+        }
+    }
+
+    #[test]
+    fn a_subscriber_only_receives_events_matching_its_filter() {
+        let bus = EventBus::new();
+
+        let mut wanted_types = HashSet::new();
+        wanted_types.insert(TransactionType::ResourceAllocation);
+        let receiver = bus.subscribe(EventFilter {
+            transaction_types: Some(wanted_types),
+            ..EventFilter::all()
+        });
+
+        bus.publish(ChainEvent::TransactionIncluded {
+            transaction: sample_transaction(TransactionType::ModuleDeployment, "AVA", None),
+        });
+        bus.publish(ChainEvent::TransactionIncluded {
+            transaction: sample_transaction(TransactionType::ResourceAllocation, "AVA", None),
+        });
+
+        let received = receiver.try_recv().unwrap();
+        assert!(matches!(
+            received,
+            ChainEvent::TransactionIncluded { transaction } if transaction.transaction_type == TransactionType::ResourceAllocation
+        ));
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[test]
+    fn entity_filter_matches_sender_recipient_and_registration() {
+        let bus = EventBus::new();
+
+        let mut entities = HashSet::new();
+        entities.insert("vault".to_string());
+        let receiver = bus.subscribe(EventFilter {
+            entities: Some(entities),
+            ..EventFilter::all()
+        });
+
+        bus.publish(ChainEvent::TransactionIncluded {
+            transaction: sample_transaction(TransactionType::ModuleDeployment, "AVA", Some("vault")),
+        });
+        bus.publish(ChainEvent::EntityRegistered { entity_id: "perception-layer".to_string() });
+
+        assert!(receiver.try_recv().is_ok());
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[test]
+    fn a_filter_with_no_constraints_matches_every_event() {
+        let bus = EventBus::new();
+        let receiver = bus.subscribe(EventFilter::all());
+
+        bus.publish(ChainEvent::BlockAdded { index: 1, hash: "abc".to_string() });
+        assert!(receiver.try_recv().is_ok());
+    }
+
+    #[test]
+    fn a_dropped_receiver_is_pruned_on_the_next_publish() {
+        let bus = EventBus::new();
+        {
+            let _receiver = bus.subscribe(EventFilter::all());
+        }
+        bus.publish(ChainEvent::BlockAdded { index: 1, hash: "abc".to_string() });
+        assert_eq!(bus.subscribers.lock().unwrap().len(), 0);
+    }
+}
@@ -1,17 +1,27 @@
 // Genesis Block Builder for AVA Constitutional Intelligence
 // Handles the creation of foundational blocks for Artifact Virtual and AVA
 
-use crate::blockchain::{Block, BlockData, Transaction, TransactionType, EntityMetadata, EntityType, Rule};
+use crate::blockchain::{Block, BlockData, Transaction, TransactionType, EntityMetadata, EntityType, Rule, transactions_merkle_root};
+use crate::engine::EngineSelector;
 use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
 use sha2::{Sha256, Digest};
+use std::fs;
+use std::path::Path;
 
 /// Genesis configuration for dual entity creation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GenesisConfig {
     pub artifact_virtual_config: EntityConfig,
     pub ava_config: EntityConfig,
+    /// Containers AVA owns, e.g. `["ava-core", "memory-core", ...]`.
+    /// `Rule::ConsensusRequired` members must be a subset of this list.
+    pub child_entities: Vec<String>,
+    /// Container registrations to run at deploy time (name + description),
+    /// previously hardcoded in `deploy_immutable_chain`.
+    pub containers: Vec<ContainerSpec>,
     pub initial_difficulty: u32,
+    pub engine: EngineSelector,
     pub genesis_timestamp: DateTime<Utc>,
 }
 
@@ -26,6 +36,49 @@ pub struct EntityConfig {
     pub constitutional_constraints: Vec<Rule>,
 }
 
+/// A single container/module to register once the genesis chain is live
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContainerSpec {
+    pub name: String,
+    pub description: String,
+}
+
+/// Declarative, on-disk definition of an entire chain's genesis, following
+/// the OpenEthereum `spec.rs` pattern so operators can fork/rebrand the
+/// chain or stand up test networks without recompiling.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChainSpec {
+    pub artifact_virtual: EntityConfig,
+    pub ava: EntityConfig,
+    pub ava_child_entities: Vec<String>,
+    pub containers: Vec<ContainerSpec>,
+    pub initial_difficulty: u32,
+    #[serde(default)]
+    pub engine: EngineSelector,
+}
+
+impl ChainSpec {
+    /// Load and parse a chain spec from a JSON file
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read chain spec {}: {}", path.display(), e))?;
+        serde_json::from_str(&contents)
+            .map_err(|e| format!("Failed to parse chain spec {}: {}", path.display(), e))
+    }
+
+    fn into_genesis_config(self) -> GenesisConfig {
+        GenesisConfig {
+            artifact_virtual_config: self.artifact_virtual,
+            ava_config: self.ava,
+            child_entities: self.ava_child_entities,
+            containers: self.containers,
+            initial_difficulty: self.initial_difficulty,
+            engine: self.engine,
+            genesis_timestamp: Utc::now(),
+        }
+    }
+}
+
 /// Genesis block builder
 pub struct GenesisBuilder {
     config: GenesisConfig,
@@ -79,7 +132,24 @@ impl GenesisBuilder {
                     Rule::ConsensusRequired(vec!["ava-core".to_string(), "memory-core".to_string()]),
                 ],
             },
+            child_entities: vec![
+                "ava-core".to_string(),
+                "memory-core".to_string(),
+                "perception-layer".to_string(),
+                "action-layer".to_string(),
+                "vault".to_string(),
+                "evolver".to_string(),
+            ],
+            containers: vec![
+                ContainerSpec { name: "ava-core".to_string(), description: "Constitutional Identity & Governance Management".to_string() },
+                ContainerSpec { name: "memory-core".to_string(), description: "Immutable Logs & Merkle Tree Structure".to_string() },
+                ContainerSpec { name: "perception-layer".to_string(), description: "Multi-modal Perception Engine".to_string() },
+                ContainerSpec { name: "action-layer".to_string(), description: "Constitutional Intelligence Execution".to_string() },
+                ContainerSpec { name: "vault".to_string(), description: "Secure Storage & Cryptographic Operations".to_string() },
+                ContainerSpec { name: "evolver".to_string(), description: "Self-improvement & Adaptive Learning".to_string() },
+            ],
             initial_difficulty: 1,
+            engine: EngineSelector::default(),
             genesis_timestamp: Utc::now(),
         };
 
@@ -91,6 +161,28 @@ impl GenesisBuilder {
         GenesisBuilder { config }
     }
 
+    /// Load a chain spec from a JSON file, validate it, and build a
+    /// `GenesisBuilder` from it. Lets operators fork/rebrand the chain or
+    /// define test networks without recompiling.
+    pub fn from_spec_file(path: &Path) -> Result<Self, String> {
+        let spec = ChainSpec::from_file(path)?;
+        let builder = GenesisBuilder {
+            config: spec.into_genesis_config(),
+        };
+        builder.validate_config()?;
+        Ok(builder)
+    }
+
+    /// The container/child entities this genesis config will register
+    pub fn child_entities(&self) -> &[String] {
+        &self.config.child_entities
+    }
+
+    /// The container registrations to run once the chain is deployed
+    pub fn containers(&self) -> &[ContainerSpec] {
+        &self.config.containers
+    }
+
     /// Build the Artifact Virtual genesis block (Block 0)
     pub fn build_artifact_virtual_genesis(&self) -> Block {
         let entity_metadata = EntityMetadata {
@@ -104,6 +196,7 @@ impl GenesisBuilder {
             parent_entity: None,
             child_entities: vec!["AVA".to_string()],
             constitutional_constraints: self.config.artifact_virtual_config.constitutional_constraints.clone(),
+            signing_keys: Vec::new(),
         };
 
         let genesis_transaction = Transaction {
@@ -115,8 +208,11 @@ impl GenesisBuilder {
             timestamp: self.config.genesis_timestamp,
             signature: self.create_genesis_signature("artifact_virtual", 0),
             constitutional_validation: true,
-        };
+            governance_authorization: None,
+            authenticator: None,
+            identity_proof: None,        };
 
+        let transactions_root = transactions_merkle_root(std::slice::from_ref(&genesis_transaction));
         Block {
             index: 0,
             timestamp: self.config.genesis_timestamp,
@@ -130,9 +226,13 @@ impl GenesisBuilder {
                     Rule::ConstitutionalAmendment,
                 ],
                 governance_actions: Vec::new(),
+                epoch_proof: None,
+                aborted_transaction_ids: Vec::new(),
             },
             nonce: 0,
             difficulty: self.config.initial_difficulty,
+            signatures: Vec::new(),
+            transactions_root,
         }
     }
 
@@ -147,15 +247,9 @@ impl GenesisBuilder {
             capabilities: self.config.ava_config.capabilities.clone(),
             vision_statement: self.config.ava_config.vision_statement.clone(),
             parent_entity: Some("Artifact Virtual".to_string()),
-            child_entities: vec![
-                "ava-core".to_string(),
-                "memory-core".to_string(),
-                "perception-layer".to_string(),
-                "action-layer".to_string(),
-                "vault".to_string(),
-                "evolver".to_string(),
-            ],
+            child_entities: self.config.child_entities.clone(),
             constitutional_constraints: self.config.ava_config.constitutional_constraints.clone(),
+            signing_keys: Vec::new(),
         };
 
         let genesis_transaction = Transaction {
@@ -167,8 +261,11 @@ impl GenesisBuilder {
             timestamp: self.config.genesis_timestamp,
             signature: self.create_genesis_signature("ava", 1),
             constitutional_validation: true,
-        };
+            governance_authorization: None,
+            authenticator: None,
+            identity_proof: None,        };
 
+        let transactions_root = transactions_merkle_root(std::slice::from_ref(&genesis_transaction));
         Block {
             index: 1,
             timestamp: self.config.genesis_timestamp,
@@ -180,14 +277,18 @@ impl GenesisBuilder {
                     Rule::ArtifactVirtualIntelligenceApproval,
                     Rule::VotingQuorum(0.67),
                     Rule::ConsensusRequired(vec![
-                        "ava-core".to_string(), 
+                        "ava-core".to_string(),
                         "memory-core".to_string()
                     ]),
                 ],
                 governance_actions: Vec::new(),
+                epoch_proof: None,
+                aborted_transaction_ids: Vec::new(),
             },
             nonce: 0,
             difficulty: self.config.initial_difficulty,
+            signatures: Vec::new(),
+            transactions_root,
         }
     }
 
@@ -257,6 +358,45 @@ impl GenesisBuilder {
             return Err("Initial difficulty must be greater than 0".to_string());
         }
 
+        // Unique entity names
+        if self.config.artifact_virtual_config.name == self.config.ava_config.name {
+            return Err("Artifact Virtual and AVA must have distinct names".to_string());
+        }
+
+        // Rule consistency: every ConsensusRequired quorum set must be
+        // non-empty and fully contained in AVA's child entities
+        for entity_config in [&self.config.artifact_virtual_config, &self.config.ava_config] {
+            for rule in &entity_config.constitutional_constraints {
+                if let Rule::ConsensusRequired(members) = rule {
+                    if members.is_empty() {
+                        return Err(format!(
+                            "{} has a ConsensusRequired rule with an empty quorum set",
+                            entity_config.name
+                        ));
+                    }
+                    for member in members {
+                        if !self.config.child_entities.contains(member) {
+                            return Err(format!(
+                                "ConsensusRequired member '{}' is not in child_entities",
+                                member
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        // Container registrations must reference unique names
+        let mut seen = std::collections::HashSet::new();
+        for container in &self.config.containers {
+            if container.name.is_empty() {
+                return Err("Container spec name cannot be empty".to_string());
+            }
+            if !seen.insert(&container.name) {
+                return Err(format!("Duplicate container name in chain spec: {}", container.name));
+            }
+        }
+
         Ok(())
     }
 
@@ -331,4 +471,56 @@ mod tests {
         assert!(stats.total_maintainers > 0);
         assert!(stats.constitutional_rules > 0);
     }
+
+    fn sample_spec_json() -> serde_json::Value {
+        serde_json::json!({
+            "artifact_virtual": {
+                "name": "Test Virtual",
+                "description": "test",
+                "maintainers": ["root@test"],
+                "capabilities": ["Testing"],
+                "vision_statement": "test",
+                "constitutional_constraints": []
+            },
+            "ava": {
+                "name": "Test AVA",
+                "description": "test",
+                "maintainers": ["ava@test"],
+                "capabilities": ["Testing"],
+                "vision_statement": "test",
+                "constitutional_constraints": [
+                    { "ConsensusRequired": ["core"] }
+                ]
+            },
+            "ava_child_entities": ["core"],
+            "containers": [{ "name": "core", "description": "core container" }],
+            "initial_difficulty": 1,
+            "engine": { "type": "ProofOfWork" }
+        })
+    }
+
+    #[test]
+    fn test_chain_spec_from_file_round_trip() {
+        let path = std::env::temp_dir().join("ava_chain_spec_test.json");
+        std::fs::write(&path, sample_spec_json().to_string()).unwrap();
+
+        let builder = GenesisBuilder::from_spec_file(&path).unwrap();
+        assert_eq!(builder.child_entities(), &["core".to_string()]);
+        assert_eq!(builder.containers().len(), 1);
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_chain_spec_rejects_consensus_member_outside_children() {
+        let mut spec = sample_spec_json();
+        spec["ava_child_entities"] = serde_json::json!([]);
+        let path = std::env::temp_dir().join("ava_chain_spec_invalid_test.json");
+        std::fs::write(&path, spec.to_string()).unwrap();
+
+        let result = GenesisBuilder::from_spec_file(&path);
+        assert!(result.is_err());
+
+        std::fs::remove_file(&path).ok();
+    }
 }
@@ -0,0 +1,126 @@
+// The concrete `IdentityProofVerifier` the doc comment on
+// `blockchain::IdentityProofVerifier` promises: an adapter over the Groth16
+// membership circuit in `zk_identity::snark_id`, wired in so
+// `Rule::AIValidation` can check a real zk-SNARK proof instead of trusting a
+// stub. Included the same way `core::backends::solidity` reaches its
+// generated bindings: a `#[path]` straight to the other tree's module,
+// rather than duplicating the circuit here.
+
+#[path = "../../../../../system/BlackNet/crypto/zk_identity/snark_id.rs"]
+mod snark_id;
+
+use crate::blockchain::IdentityProofVerifier;
+use bellman::groth16::{Proof, VerifyingKey};
+use pairing::bls12_381::Bls12;
+
+/// Checks `Transaction::identity_proof` against a `Rule::AIValidation`
+/// commitment by deserializing it as a Groth16 `Proof<Bls12>` and verifying
+/// it against `vk` and the hex-decoded commitment, via
+/// `snark_id::verify_proof`. Construct with the `VerifyingKey` produced by
+/// `snark_id::setup` for the deployment's membership circuit.
+pub struct Groth16IdentityVerifier {
+    vk: VerifyingKey<Bls12>,
+}
+
+impl Groth16IdentityVerifier {
+    pub fn new(vk: VerifyingKey<Bls12>) -> Self {
+        Groth16IdentityVerifier { vk }
+    }
+}
+
+impl IdentityProofVerifier for Groth16IdentityVerifier {
+    fn verify(&self, commitment: &str, proof: &[u8]) -> bool {
+        let Ok(commitment_bytes) = hex::decode(commitment) else {
+            return false;
+        };
+        let Ok(commitment_bytes): Result<[u8; 32], _> = commitment_bytes.try_into() else {
+            return false;
+        };
+        let Ok(proof) = Proof::<Bls12>::read(proof) else {
+            return false;
+        };
+
+        snark_id::verify_proof(&self.vk, &commitment_bytes, &proof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blockchain::{AvaBlockchain, Rule, Transaction, TransactionType};
+    use chrono::Utc;
+    use rand::rngs::OsRng;
+    use sha2::{Digest, Sha256};
+
+    #[test]
+    fn real_membership_circuit_is_checked_through_validate_transaction() {
+        let mut rng = OsRng;
+        let (params, vk) = snark_id::setup(&mut rng);
+
+        // `snark_id::setup` fixes the circuit to a 32-byte preimage.
+        let identity = b"artifact-virtual/ava-core/member".to_vec();
+        let commitment: [u8; 32] = Sha256::digest(&identity).into();
+        let commitment_hex = hex::encode(commitment);
+
+        let mut blockchain = AvaBlockchain::new();
+        blockchain.create_dual_genesis().unwrap();
+        blockchain.set_identity_verifier(Box::new(Groth16IdentityVerifier::new(vk)));
+        blockchain
+            .entities
+            .get_mut("AVA")
+            .unwrap()
+            .constitutional_constraints
+            .push(Rule::AIValidation(commitment_hex));
+
+        let mut registration = Transaction {
+            id: "register_new_module".to_string(),
+            transaction_type: TransactionType::EntityRegistration,
+            sender: "AVA".to_string(),
+            recipient: Some("new-module".to_string()),
+            data: serde_json::json!({}),
+            timestamp: Utc::now(),
+            signature: "sig".to_string(),
+            constitutional_validation: true,
+            governance_authorization: None,
+            authenticator: None,
+            identity_proof: None,
+        };
+
+        // AVA's genesis ConsensusRequired rule needs ava-core and memory-core
+        // to co-sign; `identity_proof` is outside the signed payload.
+        let co_signers: Vec<ed25519_dalek::SigningKey> = ["ava-core", "memory-core"]
+            .iter()
+            .map(|name| {
+                let key = ed25519_dalek::SigningKey::generate(&mut rng);
+                let mut entity = blockchain.entities["AVA"].clone();
+                entity.name = name.to_string();
+                entity.constitutional_constraints = Vec::new();
+                entity.signing_keys = vec![hex::encode(key.verifying_key().to_bytes())];
+                blockchain.entities.insert(name.to_string(), entity);
+                key
+            })
+            .collect();
+        let message = crate::blockchain::governance_signing_payload(&registration);
+        registration.authenticator = Some(crate::blockchain::TransactionAuthenticator::MultiEd25519 {
+            public_keys: co_signers.iter().map(|key| key.verifying_key().to_bytes()).collect(),
+            signatures: co_signers.iter().map(|key| ed25519_dalek::Signer::sign(key, &message).to_bytes()).collect(),
+            threshold: co_signers.len(),
+        });
+
+        let valid_proof = snark_id::generate_proof(&params, identity, &mut rng);
+        let mut proof_bytes = Vec::new();
+        valid_proof.write(&mut proof_bytes).unwrap();
+        registration.identity_proof = Some(proof_bytes);
+
+        assert!(blockchain.validate_transaction(&registration).unwrap());
+
+        // A proof of knowledge of a *different* preimage must not verify
+        // against this commitment.
+        let other_proof = snark_id::generate_proof(&params, b"someone-else/not-the-ava-core-id".to_vec(), &mut rng);
+        let mut other_proof_bytes = Vec::new();
+        other_proof.write(&mut other_proof_bytes).unwrap();
+        registration.identity_proof = Some(other_proof_bytes);
+
+        assert!(blockchain.validate_transaction(&registration).is_err());
+    }
+}
@@ -4,12 +4,38 @@
 pub mod blockchain;
 pub mod genesis;
 pub mod constitutional;
+pub mod engine;
+pub mod verify_queue;
+pub mod block_queue;
+pub mod tx_queue;
+pub mod snapshot;
+pub mod validator_set;
+pub mod schnorr;
+pub mod network_time;
+pub mod merkle;
+pub mod persistence;
+pub mod events;
+pub mod identity_verifier;
 
-pub use blockchain::{AvaBlockchain, Block, Transaction, EntityMetadata, BlockchainStatus};
+pub use blockchain::{AvaBlockchain, Block, Transaction, EntityMetadata, BlockchainStatus, GovernanceAuthorization, IdentityProofVerifier, transaction_leaf};
 pub use genesis::GenesisBuilder;
 pub use constitutional::{ConstitutionalEngine, GovernanceRule};
+pub use engine::{ConsensusEngine, PowEngine, BasicAuthority, QuorumBft, EngineSelector};
+pub use block_queue::{BlockQueue, QueueInfo};
+pub use tx_queue::TransactionQueue;
+pub use snapshot::SnapshotManifest;
+pub use validator_set::{EpochProof, ValidatorChange};
+pub use schnorr::{SchnorrVerifier, SchnorrSignature, GovernancePublicKey};
+pub use network_time::{ClockStatus, NetworkTimeSync, NtpSample};
+pub use merkle::{InclusionProof, verify_inclusion};
+pub use persistence::{PersistedChain, VersionedBlock, VersionedTransaction};
+pub use events::{ChainEvent, EventBus, EventFilter};
+pub use identity_verifier::Groth16IdentityVerifier;
 
 use chrono::Utc;
+use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT;
+use curve25519_dalek::scalar::Scalar;
+use rand::rngs::OsRng;
 use serde_json;
 
 /// Initialize the Artifact Virtual Intelligence blockchain with dual genesis
@@ -49,8 +75,14 @@ pub fn deploy_immutable_chain() -> Result<AvaBlockchain, String> {
         ("evolver", "Self-improvement & Adaptive Learning"),
     ];
     
+    // Single-member governance council so the demo deployment can satisfy
+    // `requires_governance_authorization` without a real multi-party quorum.
+    let council_secret = Scalar::random(&mut OsRng);
+    let council_key = RISTRETTO_BASEPOINT_POINT * council_secret;
+    blockchain.set_governance_council(vec![*council_key.compress().as_bytes()]);
+
     for (container_name, description) in containers {
-        let transaction = Transaction {
+        let mut transaction = Transaction {
             id: format!("register_{}", container_name),
             transaction_type: blockchain::TransactionType::ModuleDeployment,
             sender: "AVA".to_string(),
@@ -64,13 +96,23 @@ pub fn deploy_immutable_chain() -> Result<AvaBlockchain, String> {
             timestamp: Utc::now(),
             signature: format!("sig_{}", container_name),
             constitutional_validation: true,
-        };
-        
+            governance_authorization: None,
+            authenticator: None,
+            identity_proof: None,        };
+
+        let message = blockchain::governance_signing_payload(&transaction);
+        let signature = schnorr::sign_aggregated(&[(council_secret, council_key)], &message);
+        transaction.governance_authorization = Some(GovernanceAuthorization {
+            signers: vec![*council_key.compress().as_bytes()],
+            r: *signature.r.as_bytes(),
+            s: signature.s.to_bytes(),
+        });
+
         blockchain.add_transaction(transaction)?;
     }
     
     // Create block with container registrations
-    let container_block = blockchain.create_block()?;
+    let container_block = blockchain.create_block(&mut OsRng)?;
     println!("📦 Container Registration Block Created: {}", container_block.hash);
     
     // Final validation
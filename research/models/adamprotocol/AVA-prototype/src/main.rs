@@ -1,4 +1,7 @@
-use artifact_virtual_intelligence::{deploy_immutable_chain, AvaBlockchain};
+use artifact_virtual_intelligence::{deploy_immutable_chain, schnorr, AvaBlockchain, GovernanceAuthorization};
+use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT;
+use curve25519_dalek::scalar::Scalar;
+use rand::rngs::OsRng;
 use std::process;
 
 fn main() {
@@ -42,6 +45,12 @@ fn start_blockchain_service(mut blockchain: AvaBlockchain) -> Result<(), Box<dyn
         ("ava-security", "8443"),
     ];
 
+    // Single-member governance council so this service's own container
+    // registrations satisfy `requires_governance_authorization`.
+    let council_secret = Scalar::random(&mut OsRng);
+    let council_key = RISTRETTO_BASEPOINT_POINT * council_secret;
+    blockchain.set_governance_council(vec![*council_key.compress().as_bytes()]);
+
     for (container_name, port) in containers {
         let entity_id = format!("container_{}", container_name);
         let metadata = serde_json::json!({
@@ -52,13 +61,21 @@ fn start_blockchain_service(mut blockchain: AvaBlockchain) -> Result<(), Box<dyn
         });
 
         // Create registration transaction
-        let transaction = blockchain.create_transaction(
+        let mut transaction = blockchain.create_transaction(
             "system".to_string(),
             entity_id.clone(),
             "register_container".to_string(),
             metadata,
         )?;
 
+        let message = artifact_virtual_intelligence::blockchain::governance_signing_payload(&transaction);
+        let signature = schnorr::sign_aggregated(&[(council_secret, council_key)], &message);
+        transaction.governance_authorization = Some(GovernanceAuthorization {
+            signers: vec![*council_key.compress().as_bytes()],
+            r: *signature.r.as_bytes(),
+            s: signature.s.to_bytes(),
+        });
+
         // Add transaction to blockchain
         blockchain.add_transaction(transaction)?;
         println!("📝 Registered container: {}", container_name);
@@ -66,7 +83,7 @@ fn start_blockchain_service(mut blockchain: AvaBlockchain) -> Result<(), Box<dyn
 
     // Mine a block with all container registrations
     println!("⛏️  Mining registration block...");
-    blockchain.mine_pending_transactions("system".to_string())?;
+    blockchain.mine_pending_transactions("system".to_string(), &mut OsRng)?;
     
     // Save blockchain state
     blockchain.save_to_file("blockchain_state.json")?;
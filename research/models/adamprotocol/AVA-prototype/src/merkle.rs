@@ -0,0 +1,150 @@
+// Merkle Transaction Accumulator
+// A flat `Vec<Transaction>` only lets a verifier check inclusion by
+// reading the whole block. Folding each block's transactions into a
+// binary Merkle tree lets an external light client confirm a single
+// transaction was included given only the block header's
+// `transactions_root` and a logarithmic-size `InclusionProof`.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Hashes a transaction's canonical bytes into a Merkle leaf.
+pub fn leaf_hash(canonical_bytes: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(canonical_bytes);
+    hasher.finalize().into()
+}
+
+fn parent_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Folds one level of the tree into the next, duplicating the last node
+/// when the level has odd length.
+fn fold_level(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    level
+        .chunks(2)
+        .map(|pair| parent_hash(&pair[0], pair.get(1).unwrap_or(&pair[0])))
+        .collect()
+}
+
+/// Folds `leaves` up to a single Merkle root, duplicating the last leaf at
+/// any level with an odd number of nodes. Returns the zero hash for an
+/// empty block so `transactions_root` stays well-defined.
+pub fn merkle_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    if leaves.is_empty() {
+        return [0u8; 32];
+    }
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = fold_level(&level);
+    }
+    level[0]
+}
+
+/// Which side of the parent hash a sibling sits on, so `verify_inclusion`
+/// folds each step in the right order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+/// One sibling hash on the path from a leaf up to the root.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProofStep {
+    pub sibling: [u8; 32],
+    pub side: Side,
+}
+
+/// A leaf's index plus every sibling hash on the path to the root —
+/// enough for `verify_inclusion` to recompute `transactions_root` from a
+/// single transaction without the rest of the block.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InclusionProof {
+    pub leaf_index: usize,
+    pub steps: Vec<ProofStep>,
+}
+
+/// Builds the inclusion proof for the leaf at `leaf_index`, or `None` if
+/// `leaves` is empty or `leaf_index` is out of range.
+pub fn merkle_proof(leaves: &[[u8; 32]], leaf_index: usize) -> Option<InclusionProof> {
+    if leaf_index >= leaves.len() {
+        return None;
+    }
+
+    let mut level = leaves.to_vec();
+    let mut index = leaf_index;
+    let mut steps = Vec::new();
+    while level.len() > 1 {
+        let (sibling_index, side) = if index % 2 == 0 {
+            (index + 1, Side::Right)
+        } else {
+            (index - 1, Side::Left)
+        };
+        let sibling = *level.get(sibling_index).unwrap_or(&level[index]);
+        steps.push(ProofStep { sibling, side });
+
+        level = fold_level(&level);
+        index /= 2;
+    }
+
+    Some(InclusionProof { leaf_index, steps })
+}
+
+/// Recomputes the Merkle root from `tx_leaf` and `proof`'s sibling path
+/// and checks it matches `root` — the check an external verifier runs
+/// given only a block header and a transaction.
+pub fn verify_inclusion(root: &[u8; 32], tx_leaf: [u8; 32], proof: &InclusionProof) -> bool {
+    let mut hash = tx_leaf;
+    for step in &proof.steps {
+        hash = match step.side {
+            Side::Left => parent_hash(&step.sibling, &hash),
+            Side::Right => parent_hash(&hash, &step.sibling),
+        };
+    }
+    hash == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaves(count: usize) -> Vec<[u8; 32]> {
+        (0..count).map(|i| leaf_hash(format!("tx-{i}").as_bytes())).collect()
+    }
+
+    #[test]
+    fn empty_tree_has_the_zero_root() {
+        assert_eq!(merkle_root(&[]), [0u8; 32]);
+    }
+
+    #[test]
+    fn every_leaf_in_an_odd_length_tree_proves_against_the_root() {
+        let leaves = leaves(5);
+        let root = merkle_root(&leaves);
+
+        for (index, leaf) in leaves.iter().enumerate() {
+            let proof = merkle_proof(&leaves, index).unwrap();
+            assert!(verify_inclusion(&root, *leaf, &proof));
+        }
+    }
+
+    #[test]
+    fn a_proof_for_a_different_leaf_fails() {
+        let leaves = leaves(4);
+        let root = merkle_root(&leaves);
+        let proof = merkle_proof(&leaves, 0).unwrap();
+
+        assert!(!verify_inclusion(&root, leaves[1], &proof));
+    }
+
+    #[test]
+    fn out_of_range_index_has_no_proof() {
+        let leaves = leaves(3);
+        assert!(merkle_proof(&leaves, 3).is_none());
+    }
+}
@@ -0,0 +1,181 @@
+// Network Time Synchronization for the AVA Chain
+// Block timestamps have always trusted the local wall clock, which drifts
+// across nodes and makes `is_chain_valid`/`import_queued_blocks` unreliable
+// once more than one node is sealing blocks. This mirrors the classic NTP
+// client algorithm: poll a handful of time servers, fold their
+// request/response round trips into an offset and drift estimate, and fall
+// back to "unsynced" rather than panicking when every server is
+// unreachable.
+
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Arc;
+
+/// One NTP-style request/response round trip: `t0`/`t3` are this node's own
+/// clock when the request left and the response arrived; `t1`/`t2` are the
+/// server's clock when it received the request and sent the response.
+#[derive(Debug, Clone, Copy)]
+pub struct NtpSample {
+    pub t0: DateTime<Utc>,
+    pub t1: DateTime<Utc>,
+    pub t2: DateTime<Utc>,
+    pub t3: DateTime<Utc>,
+}
+
+impl NtpSample {
+    /// The standard NTP clock offset: `((t1 - t0) + (t2 - t3)) / 2`.
+    pub fn offset(&self) -> Duration {
+        ((self.t1 - self.t0) + (self.t2 - self.t3)) / 2
+    }
+
+    /// The standard NTP round-trip delay: `(t3 - t0) - (t2 - t1)`.
+    pub fn round_trip(&self) -> Duration {
+        (self.t3 - self.t0) - (self.t2 - self.t1)
+    }
+}
+
+/// Queries a single NTP server by address and returns its round-trip
+/// sample, or an error if it's unreachable. Injected rather than hitting a
+/// real socket so callers (and tests) can simulate servers deterministically.
+pub type NtpQuery = Arc<dyn Fn(&str) -> Result<NtpSample, String> + Send + Sync>;
+
+/// Whether `NetworkTimeSync` has a usable offset yet.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClockStatus {
+    /// No NTP server has ever answered; `network_time` falls back to the
+    /// local clock unmodified.
+    Unsynced,
+    Synced {
+        offset: Duration,
+        drift: Duration,
+        confidence: f32,
+    },
+}
+
+/// Tracks the offset between this node's local clock and network time,
+/// derived from polling `servers` with `query`.
+pub struct NetworkTimeSync {
+    servers: Vec<String>,
+    query: NtpQuery,
+    status: ClockStatus,
+    last_sync: Option<DateTime<Utc>>,
+}
+
+impl NetworkTimeSync {
+    /// Build a clock that polls `servers` via `query` when `sync` is
+    /// called; starts `Unsynced` until the first successful poll.
+    pub fn new(servers: Vec<String>, query: NtpQuery) -> Self {
+        NetworkTimeSync {
+            servers,
+            query,
+            status: ClockStatus::Unsynced,
+            last_sync: None,
+        }
+    }
+
+    /// A clock with no configured servers, permanently `Unsynced`. The
+    /// default for a freshly constructed `AvaBlockchain`.
+    pub fn disabled() -> Self {
+        NetworkTimeSync::new(Vec::new(), Arc::new(|server| Err(format!("no route to NTP server {}", server))))
+    }
+
+    /// Query every configured server and fold the successful samples into
+    /// a new offset/drift/confidence estimate. Servers that error are
+    /// skipped; if every server is unreachable the clock falls back to
+    /// `ClockStatus::Unsynced` rather than panicking or keeping stale state.
+    pub fn sync(&mut self, now: DateTime<Utc>) -> ClockStatus {
+        let samples: Vec<NtpSample> = self.servers.iter().filter_map(|server| (self.query)(server).ok()).collect();
+
+        if samples.is_empty() {
+            self.status = ClockStatus::Unsynced;
+            return self.status;
+        }
+
+        let previous_offset = match self.status {
+            ClockStatus::Synced { offset, .. } => Some(offset),
+            ClockStatus::Unsynced => None,
+        };
+
+        let total = samples.iter().fold(Duration::zero(), |acc, sample| acc + sample.offset());
+        let mean_offset = total / samples.len() as i32;
+        let drift = previous_offset.map(|prev| mean_offset - prev).unwrap_or_else(Duration::zero);
+        let confidence = (samples.len() as f32 / self.servers.len() as f32).min(1.0);
+
+        self.status = ClockStatus::Synced {
+            offset: mean_offset,
+            drift,
+            confidence,
+        };
+        self.last_sync = Some(now);
+        self.status
+    }
+
+    /// The best estimate of network time: `local_now` corrected by the
+    /// current offset, or `local_now` unmodified while unsynced.
+    pub fn network_time(&self, local_now: DateTime<Utc>) -> DateTime<Utc> {
+        match self.status {
+            ClockStatus::Synced { offset, .. } => local_now + offset,
+            ClockStatus::Unsynced => local_now,
+        }
+    }
+
+    pub fn status(&self) -> ClockStatus {
+        self.status
+    }
+
+    pub fn last_sync(&self) -> Option<DateTime<Utc>> {
+        self.last_sync
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(offset: Duration) -> NtpSample {
+        let t0 = Utc::now();
+        let round_trip = Duration::milliseconds(20);
+        NtpSample {
+            t0,
+            t1: t0 + offset + round_trip / 2,
+            t2: t0 + offset + round_trip / 2,
+            t3: t0 + round_trip,
+        }
+    }
+
+    #[test]
+    fn offset_matches_the_injected_server_offset() {
+        let expected = Duration::milliseconds(250);
+        let measured = sample(expected).offset();
+        assert!((measured - expected).num_milliseconds().abs() <= 1);
+    }
+
+    #[test]
+    fn sync_averages_multiple_servers_and_tracks_drift() {
+        let query: NtpQuery = Arc::new(|server: &str| match server {
+            "ntp-a" => Ok(sample(Duration::milliseconds(100))),
+            "ntp-b" => Ok(sample(Duration::milliseconds(300))),
+            _ => Err(format!("unknown server {}", server)),
+        });
+        let mut clock = NetworkTimeSync::new(vec!["ntp-a".to_string(), "ntp-b".to_string()], query);
+
+        let first = clock.sync(Utc::now());
+        match first {
+            ClockStatus::Synced { offset, confidence, .. } => {
+                assert!((offset.num_milliseconds() - 200).abs() <= 1);
+                assert_eq!(confidence, 1.0);
+            }
+            ClockStatus::Unsynced => panic!("expected a synced status"),
+        }
+        assert!(clock.last_sync().is_some());
+    }
+
+    #[test]
+    fn unreachable_servers_fall_back_to_unsynced_instead_of_panicking() {
+        let query: NtpQuery = Arc::new(|server: &str| Err(format!("timed out contacting {}", server)));
+        let mut clock = NetworkTimeSync::new(vec!["ntp-a".to_string()], query);
+
+        assert_eq!(clock.sync(Utc::now()), ClockStatus::Unsynced);
+        let now = Utc::now();
+        assert_eq!(clock.network_time(now), now);
+    }
+}
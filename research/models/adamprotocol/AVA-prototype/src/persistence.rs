@@ -0,0 +1,162 @@
+// Versioned On-Disk Chain Format
+// `Block` and `Transaction` serialize as bare structs, so any field added
+// or removed later would silently break deserialization of a previously
+// saved chain. Every persisted block/transaction is instead wrapped in a
+// `Versioned*` enum tagged with its format version; `save_to_file` always
+// writes the latest tag, and `load_from_file` upgrades whatever tag it
+// reads into the latest in-memory representation through an explicit
+// `into_v1` (and, once a V2 exists, `V2::from_v1`) migration rather than
+// failing outright on an older file.
+
+use crate::blockchain::{AvaBlockchain, Block, EntityMetadata, Transaction};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// `Block` as it's written to disk today. A future format change adds a
+/// `BlockV2` here and an upgrade arm to `VersionedBlock::into_v1`/a new
+/// `into_v2`, instead of editing `BlockV1` in place.
+pub type BlockV1 = Block;
+
+/// `Transaction` as it's written to disk today; see `BlockV1`.
+pub type TransactionV1 = Transaction;
+
+/// Envelope every persisted block is wrapped in, tagged with its format
+/// version so `load_from_file` knows which migration path to run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "version")]
+pub enum VersionedBlock {
+    V1(BlockV1),
+}
+
+impl VersionedBlock {
+    /// Upgrade to the latest in-memory `Block` representation. Today V1
+    /// *is* latest, so this is the identity function; a `V2` arm would
+    /// migrate a `V1` payload forward instead of rejecting it.
+    pub fn into_v1(self) -> BlockV1 {
+        match self {
+            VersionedBlock::V1(block) => block,
+        }
+    }
+}
+
+impl From<Block> for VersionedBlock {
+    fn from(block: Block) -> Self {
+        VersionedBlock::V1(block)
+    }
+}
+
+/// Envelope every persisted transaction is wrapped in; see `VersionedBlock`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "version")]
+pub enum VersionedTransaction {
+    V1(TransactionV1),
+}
+
+impl VersionedTransaction {
+    /// Upgrade to the latest in-memory `Transaction` representation; see
+    /// `VersionedBlock::into_v1`.
+    pub fn into_v1(self) -> TransactionV1 {
+        match self {
+            VersionedTransaction::V1(transaction) => transaction,
+        }
+    }
+}
+
+impl From<Transaction> for VersionedTransaction {
+    fn from(transaction: Transaction) -> Self {
+        VersionedTransaction::V1(transaction)
+    }
+}
+
+/// The full on-disk envelope `save_to_file`/`load_from_file` read and
+/// write: every block and pending transaction wrapped in its versioned
+/// form, alongside the entity registry and genesis flag, which aren't
+/// versioned separately since they change in lockstep with the chain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersistedChain {
+    pub chain: Vec<VersionedBlock>,
+    pub pending_transactions: Vec<VersionedTransaction>,
+    pub entities: HashMap<String, EntityMetadata>,
+    pub genesis_created: bool,
+}
+
+impl AvaBlockchain {
+    /// Save the chain to `filename` as a versioned envelope so it can be
+    /// loaded back by a future build whose `Block`/`Transaction` have grown
+    /// new fields.
+    pub fn save_to_file(&self, filename: &str) -> Result<(), String> {
+        let persisted = PersistedChain {
+            chain: self.chain.iter().cloned().map(VersionedBlock::from).collect(),
+            pending_transactions: self
+                .pending_transactions
+                .iter()
+                .cloned()
+                .map(VersionedTransaction::from)
+                .collect(),
+            entities: self.entities.clone(),
+            genesis_created: self.genesis_created,
+        };
+
+        let json = serde_json::to_string_pretty(&persisted)
+            .map_err(|e| format!("Serialization error: {}", e))?;
+
+        std::fs::write(filename, json).map_err(|e| format!("File write error: {}", e))
+    }
+
+    /// Load a chain previously written by `save_to_file`, upgrading every
+    /// block and transaction to the latest representation regardless of
+    /// which version it was saved under. The returned chain uses the
+    /// default PoW engine; callers that persisted a different engine
+    /// selector need to re-apply it with `with_engine`/`set_*` after load.
+    pub fn load_from_file(filename: &str) -> Result<Self, String> {
+        let contents =
+            std::fs::read_to_string(filename).map_err(|e| format!("File read error: {}", e))?;
+        let persisted: PersistedChain = serde_json::from_str(&contents)
+            .map_err(|e| format!("Deserialization error: {}", e))?;
+
+        let mut blockchain = AvaBlockchain::new();
+        blockchain.chain = persisted.chain.into_iter().map(VersionedBlock::into_v1).collect();
+        blockchain.pending_transactions = persisted
+            .pending_transactions
+            .into_iter()
+            .map(VersionedTransaction::into_v1)
+            .collect();
+        blockchain.entities = persisted.entities;
+        blockchain.genesis_created = persisted.genesis_created;
+
+        Ok(blockchain)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn save_and_load_round_trips_the_chain() {
+        let mut source = AvaBlockchain::new();
+        source.create_dual_genesis().unwrap();
+
+        let path = std::env::temp_dir().join("ava_persistence_round_trip_test.json");
+        source.save_to_file(path.to_str().unwrap()).unwrap();
+
+        let restored = AvaBlockchain::load_from_file(path.to_str().unwrap()).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(restored.chain.len(), source.chain.len());
+        assert_eq!(restored.entities.len(), source.entities.len());
+        assert_eq!(restored.genesis_created, source.genesis_created);
+    }
+
+    #[test]
+    fn an_unrecognized_version_tag_fails_to_deserialize_rather_than_silently_losing_data() {
+        let malformed = r#"{"chain":[{"version":"V99"}],"pending_transactions":[],"entities":{},"genesis_created":false}"#;
+        let path = std::env::temp_dir().join("ava_persistence_bad_version_test.json");
+        std::fs::write(&path, malformed).unwrap();
+
+        let result = AvaBlockchain::load_from_file(path.to_str().unwrap());
+        std::fs::remove_file(&path).ok();
+
+        assert!(result.is_err());
+    }
+}
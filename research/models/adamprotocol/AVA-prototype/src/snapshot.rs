@@ -0,0 +1,209 @@
+// Chunked Snapshot + Restore for the AVA Chain
+// Inspired by Parity's warp-sync snapshots: the chain and entity state are
+// split into fixed-size, independently-hashed chunks so a new node can
+// verify and resume an import instead of trusting one monolithic dump.
+
+use crate::blockchain::{AvaBlockchain, Block, EntityMetadata};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// Number of blocks bundled into a single snapshot chunk
+pub const SNAPSHOT_CHUNK_SIZE: usize = 16;
+
+const BLACKLIST_FILE: &str = "ava_snapshot_blacklist.json";
+
+/// Describes a snapshot without carrying its bulk data: chunk hashes,
+/// the state root they fold up to, and which block the snapshot was
+/// taken at.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotManifest {
+    pub chunk_hashes: Vec<String>,
+    pub state_root: String,
+    pub block_index: u64,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl SnapshotManifest {
+    /// Stable identifier for this manifest, used for blacklisting
+    pub fn manifest_hash(&self) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.state_root.as_bytes());
+        for chunk_hash in &self.chunk_hashes {
+            hasher.update(chunk_hash.as_bytes());
+        }
+        hasher.update(self.block_index.to_string().as_bytes());
+        format!("{:x}", hasher.finalize())
+    }
+}
+
+fn hash_bytes(bytes: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    format!("{:x}", hasher.finalize())
+}
+
+impl AvaBlockchain {
+    /// Split the chain into fixed-size block chunks plus a trailing entity
+    /// chunk, hash each one, and return the manifest alongside the raw
+    /// chunk bytes so they can be shipped to a new node.
+    pub fn take_snapshot(&self) -> (SnapshotManifest, Vec<Vec<u8>>) {
+        let mut chunks: Vec<Vec<u8>> = self
+            .chain
+            .chunks(SNAPSHOT_CHUNK_SIZE)
+            .map(|blocks| serde_json::to_vec(blocks).unwrap_or_default())
+            .collect();
+        chunks.push(serde_json::to_vec(&self.entities).unwrap_or_default());
+
+        let chunk_hashes: Vec<String> = chunks.iter().map(|c| hash_bytes(c)).collect();
+        let state_root = fold_state_root(&chunk_hashes);
+
+        let manifest = SnapshotManifest {
+            chunk_hashes,
+            state_root,
+            block_index: self.chain.last().map(|b| b.index).unwrap_or(0),
+            timestamp: Utc::now(),
+        };
+
+        (manifest, chunks)
+    }
+
+    /// Verify every chunk against the manifest and rebuild the chain from
+    /// them. A manifest whose chunks fail to verify or deserialize is
+    /// rejected and permanently blacklisted so it is never retried.
+    pub fn restore_from_manifest(
+        &mut self,
+        manifest: &SnapshotManifest,
+        chunks: Vec<Vec<u8>>,
+    ) -> Result<(), String> {
+        let manifest_hash = manifest.manifest_hash();
+        if self.blacklisted_manifests.contains(&manifest_hash) {
+            return Err(format!("manifest {} is blacklisted", manifest_hash));
+        }
+
+        if let Err(e) = self.try_restore(manifest, &chunks) {
+            self.blacklist_manifest(&manifest_hash);
+            return Err(e);
+        }
+
+        Ok(())
+    }
+
+    fn try_restore(
+        &mut self,
+        manifest: &SnapshotManifest,
+        chunks: &[Vec<u8>],
+    ) -> Result<(), String> {
+        if chunks.len() != manifest.chunk_hashes.len() {
+            return Err(format!(
+                "expected {} chunks, got {}",
+                manifest.chunk_hashes.len(),
+                chunks.len()
+            ));
+        }
+
+        for (chunk, expected_hash) in chunks.iter().zip(&manifest.chunk_hashes) {
+            let actual_hash = hash_bytes(chunk);
+            if &actual_hash != expected_hash {
+                return Err(format!(
+                    "chunk hash mismatch: expected {}, got {}",
+                    expected_hash, actual_hash
+                ));
+            }
+        }
+
+        if fold_state_root(&manifest.chunk_hashes) != manifest.state_root {
+            return Err("state root does not match chunk hashes".to_string());
+        }
+
+        let (entity_chunk, block_chunks) = chunks
+            .split_last()
+            .ok_or("snapshot has no chunks")?;
+
+        let entities: HashMap<String, EntityMetadata> = serde_json::from_slice(entity_chunk)
+            .map_err(|e| format!("failed to deserialize entity chunk: {}", e))?;
+
+        let mut chain: Vec<Block> = Vec::new();
+        for chunk in block_chunks {
+            let mut blocks: Vec<Block> = serde_json::from_slice(chunk)
+                .map_err(|e| format!("failed to deserialize block chunk: {}", e))?;
+            chain.append(&mut blocks);
+        }
+
+        for window in chain.windows(2) {
+            self.engine.verify_seal(&window[1], &window[0])?;
+        }
+
+        self.chain = chain;
+        self.entities = entities;
+        self.genesis_created = !self.chain.is_empty();
+
+        Ok(())
+    }
+
+    fn blacklist_manifest(&mut self, manifest_hash: &str) {
+        self.blacklisted_manifests.insert(manifest_hash.to_string());
+        let _ = self.persist_blacklist(Path::new(BLACKLIST_FILE));
+    }
+
+    fn persist_blacklist(&self, path: &Path) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(&self.blacklisted_manifests)
+            .map_err(|e| format!("failed to serialize blacklist: {}", e))?;
+        std::fs::write(path, json).map_err(|e| format!("failed to write blacklist: {}", e))
+    }
+
+    /// Load a previously-persisted set of blacklisted manifest hashes
+    pub fn load_blacklist(path: &Path) -> HashSet<String> {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+}
+
+/// Fold chunk hashes up to a single state root the same way on both the
+/// taking and restoring side.
+fn fold_state_root(chunk_hashes: &[String]) -> String {
+    let mut hasher = Sha256::new();
+    for chunk_hash in chunk_hashes {
+        hasher.update(chunk_hash.as_bytes());
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshot_round_trips() {
+        let mut source = AvaBlockchain::new();
+        source.create_dual_genesis().unwrap();
+
+        let (manifest, chunks) = source.take_snapshot();
+
+        let mut restored = AvaBlockchain::new();
+        restored.restore_from_manifest(&manifest, chunks).unwrap();
+
+        assert_eq!(restored.chain.len(), source.chain.len());
+        assert_eq!(restored.entities.len(), source.entities.len());
+    }
+
+    #[test]
+    fn corrupted_chunk_is_rejected_and_blacklisted() {
+        let mut source = AvaBlockchain::new();
+        source.create_dual_genesis().unwrap();
+        let (manifest, mut chunks) = source.take_snapshot();
+        chunks[0] = b"not the real chunk".to_vec();
+
+        let mut target = AvaBlockchain::new();
+        let first_attempt = target.restore_from_manifest(&manifest, chunks.clone());
+        assert!(first_attempt.is_err());
+        assert!(target.blacklisted_manifests.contains(&manifest.manifest_hash()));
+
+        let second_attempt = target.restore_from_manifest(&manifest, chunks);
+        assert!(second_attempt.is_err());
+    }
+}
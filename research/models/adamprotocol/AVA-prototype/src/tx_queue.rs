@@ -0,0 +1,111 @@
+// Parallel Transaction Verification Queue
+// Sits between `add_transaction` and `mine_pending_transactions`, mirroring
+// `crate::block_queue::BlockQueue`'s three-stage pipeline so mining many
+// container-registration transactions does not block on verifying them
+// one at a time. Both queues wrap the same `crate::verify_queue::VerifyQueue`
+// pipeline; this is just the `Transaction`-typed façade over it.
+
+use crate::blockchain::Transaction;
+use crate::verify_queue::{worker_count, QueueInfo, VerifyQueue};
+use std::sync::Arc;
+
+/// Signature/PoW/metadata checks run by every worker, supplied by whoever
+/// owns the queue (usually `AvaBlockchain`).
+pub type TransactionVerifier = Arc<dyn Fn(&Transaction) -> Result<(), String> + Send + Sync>;
+
+/// Concurrent transaction-verification pipeline: `unverified -> verifying
+/// -> verified`. Mining only ever pulls from `verified`, in arrival order.
+pub struct TransactionQueue(VerifyQueue<Transaction>);
+
+impl TransactionQueue {
+    /// Spawn a queue backed by `max(available_parallelism, 3) - 2` worker
+    /// threads, each running `verifier` against popped transactions.
+    pub fn new(verifier: TransactionVerifier) -> Self {
+        Self::with_worker_count(worker_count(), verifier)
+    }
+
+    pub fn with_worker_count(workers: usize, verifier: TransactionVerifier) -> Self {
+        TransactionQueue(VerifyQueue::with_worker_count(
+            workers,
+            verifier,
+            Arc::new(|transaction: &Transaction| transaction.id.clone()),
+        ))
+    }
+
+    /// Enqueue a transaction for verification. A transaction whose id is
+    /// already queued, in flight, or verified is silently ignored.
+    pub fn push(&self, transaction: Transaction) {
+        self.0.push(transaction)
+    }
+
+    /// Drain every verified transaction, in the order it was originally
+    /// pushed
+    pub fn drain_verified(&self) -> Vec<Transaction> {
+        self.0.drain_verified()
+    }
+
+    pub fn info(&self) -> QueueInfo {
+        self.0.info()
+    }
+
+    /// Block the calling thread until no work remains unverified or
+    /// in-flight (verified results may still be waiting to be drained)
+    pub fn wait_until_empty(&self) {
+        self.0.wait_until_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blockchain::TransactionType;
+    use chrono::Utc;
+
+    fn sample_tx(id: &str) -> Transaction {
+        Transaction {
+            id: id.to_string(),
+            transaction_type: TransactionType::ModuleDeployment,
+            sender: "AVA".to_string(),
+            recipient: None,
+            data: serde_json::json!({}),
+            timestamp: Utc::now(),
+            signature: format!("sig_{}", id),
+            constitutional_validation: true,
+            governance_authorization: None,
+            authenticator: None,
+            identity_proof: None,        }
+    }
+
+    #[test]
+    fn verified_transactions_drain_in_arrival_order() {
+        let verifier: TransactionVerifier = Arc::new(|_tx: &Transaction| Ok(()));
+        let queue = TransactionQueue::with_worker_count(2, verifier);
+
+        queue.push(sample_tx("tx_0"));
+        queue.push(sample_tx("tx_1"));
+        queue.wait_until_empty();
+
+        let verified = queue.drain_verified();
+        assert_eq!(verified.len(), 2);
+        assert_eq!(verified[0].id, "tx_0");
+        assert_eq!(verified[1].id, "tx_1");
+    }
+
+    #[test]
+    fn failed_verification_never_reaches_verified() {
+        let verifier: TransactionVerifier = Arc::new(|tx: &Transaction| {
+            if tx.signature.is_empty() {
+                Err("missing signature".to_string())
+            } else {
+                Ok(())
+            }
+        });
+        let queue = TransactionQueue::with_worker_count(1, verifier);
+        let mut unsigned = sample_tx("tx_bad");
+        unsigned.signature = String::new();
+        queue.push(unsigned);
+        queue.wait_until_empty();
+
+        assert_eq!(queue.drain_verified().len(), 0);
+    }
+}
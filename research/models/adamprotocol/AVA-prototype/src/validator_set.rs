@@ -0,0 +1,233 @@
+// Epoch-Based Validator Set for ConsensusRequired
+// Following OpenEthereum's validator-set / epoch-transition design: the
+// active signer set is read from the chain itself rather than fixed in
+// the genesis config, and membership changes are recorded as an
+// `EpochProof` so historical blocks stay valid against the validator set
+// that was active when they were sealed.
+
+use crate::blockchain::{AvaBlockchain, Rule, Transaction};
+use serde::{Deserialize, Serialize};
+
+/// A single addition/removal of a container from the consensus-required
+/// validator set
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ValidatorChange {
+    Add(String),
+    Remove(String),
+}
+
+/// Recorded on the block that performs an epoch transition: the resulting
+/// validator set and the change that produced it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EpochProof {
+    pub epoch: u64,
+    pub start_block: u64,
+    pub validators: Vec<String>,
+    pub change: ValidatorChange,
+}
+
+impl AvaBlockchain {
+    /// The initial validator set, read from AVA's `Rule::ConsensusRequired`
+    /// constraint recorded at genesis
+    fn genesis_validators(&self) -> Vec<String> {
+        self.entities
+            .get("AVA")
+            .into_iter()
+            .flat_map(|ava| &ava.constitutional_constraints)
+            .find_map(|rule| match rule {
+                Rule::ConsensusRequired(members) => Some(members.clone()),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    /// The validator set active at `at_block`: the genesis set, overridden
+    /// by the most recent `EpochProof` at or before that block
+    pub fn active_validators(&self, at_block: u64) -> Vec<String> {
+        let mut validators = self.genesis_validators();
+        for block in &self.chain {
+            if block.index > at_block {
+                break;
+            }
+            if let Some(proof) = &block.data.epoch_proof {
+                validators = proof.validators.clone();
+            }
+        }
+        validators
+    }
+
+    /// Verify a block's seal against the validator set that was active at
+    /// its own epoch, not the chain's current one, so historical blocks
+    /// stay valid after later membership changes.
+    pub fn verify_block_family(&self, block_index: u64) -> Result<(), String> {
+        let block = self
+            .chain
+            .iter()
+            .find(|b| b.index == block_index)
+            .ok_or_else(|| format!("no block at index {}", block_index))?;
+        let parent = if block_index == 0 {
+            return Ok(());
+        } else {
+            self.chain
+                .iter()
+                .find(|b| b.index == block_index - 1)
+                .ok_or("parent block not found")?
+        };
+
+        self.engine.verify_seal(block, parent)?;
+
+        let validators_at_epoch = self.active_validators(block_index.saturating_sub(1));
+        if validators_at_epoch.is_empty() {
+            return Ok(());
+        }
+        let signer_ids = self.engine.expected_signers(block);
+        if signer_ids
+            .iter()
+            .all(|signer| !validators_at_epoch.contains(signer))
+        {
+            return Err(format!(
+                "block {} was not sealed by the validator set active at its epoch",
+                block_index
+            ));
+        }
+        Ok(())
+    }
+
+    /// Record a `ValidatorChange` as an epoch transition. The governance
+    /// transaction carrying the change must itself satisfy the chain's
+    /// current `VotingQuorum` before the transition is admitted, mirroring
+    /// how any other constitutional amendment is gated.
+    pub fn apply_epoch_transition(
+        &mut self,
+        governance_transaction: Transaction,
+        change: ValidatorChange,
+        rng: &mut impl rand::Rng,
+    ) -> Result<crate::blockchain::Block, String> {
+        if !governance_transaction.constitutional_validation {
+            return Err(
+                "validator set change must satisfy the current VotingQuorum".to_string(),
+            );
+        }
+
+        let mut new_validators = self.active_validators(
+            self.chain.last().map(|b| b.index).unwrap_or(0),
+        );
+        match &change {
+            ValidatorChange::Add(id) => {
+                if !new_validators.contains(id) {
+                    new_validators.push(id.clone());
+                }
+            }
+            ValidatorChange::Remove(id) => {
+                new_validators.retain(|v| v != id);
+            }
+        }
+
+        self.add_transaction(governance_transaction)?;
+        let mut block = self.create_block(rng)?;
+
+        // The block was already sealed without the epoch proof; attach it
+        // and reseal so `hash` covers the final contents.
+        let epoch = self
+            .chain
+            .iter()
+            .filter(|b| b.data.epoch_proof.is_some())
+            .count() as u64
+            + 1;
+        block.data.epoch_proof = Some(EpochProof {
+            epoch,
+            start_block: block.index,
+            validators: new_validators,
+            change,
+        });
+        block.hash = String::new();
+        block.nonce = 0;
+        block.signatures = Vec::new();
+        self.engine.seal_block(&mut block)?;
+
+        if let Some(last) = self.chain.last_mut() {
+            *last = block.clone();
+        }
+
+        Ok(block)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blockchain::TransactionType;
+    use chrono::Utc;
+
+    fn governance_tx() -> Transaction {
+        Transaction {
+            id: "gov_add_validator".to_string(),
+            transaction_type: TransactionType::GovernanceAction,
+            sender: "AVA".to_string(),
+            recipient: None,
+            data: serde_json::json!({"action": "add_validator"}),
+            timestamp: Utc::now(),
+            signature: "sig_gov".to_string(),
+            constitutional_validation: true,
+            governance_authorization: None,
+            authenticator: None,
+            identity_proof: None,        }
+    }
+
+    #[test]
+    fn genesis_validators_match_consensus_required() {
+        let mut chain = AvaBlockchain::new();
+        chain.create_dual_genesis().unwrap();
+        let validators = chain.active_validators(chain.chain.last().unwrap().index);
+        assert!(validators.contains(&"ava-core".to_string()));
+        assert!(validators.contains(&"memory-core".to_string()));
+    }
+
+    #[test]
+    fn epoch_transition_adds_validator_and_is_rejected_without_quorum() {
+        let mut chain = AvaBlockchain::new();
+        chain.create_dual_genesis().unwrap();
+
+        let mut unauthorized = governance_tx();
+        unauthorized.constitutional_validation = false;
+        let rejected = chain.apply_epoch_transition(
+            unauthorized,
+            ValidatorChange::Add("perception-layer".to_string()),
+            &mut rand::rngs::OsRng,
+        );
+        assert!(rejected.is_err());
+
+        // AVA's genesis ConsensusRequired rule needs ava-core and memory-core
+        // to co-sign the governance transaction.
+        let mut authorized = governance_tx();
+        let co_signers: Vec<ed25519_dalek::SigningKey> = ["ava-core", "memory-core"]
+            .iter()
+            .map(|name| {
+                let key = ed25519_dalek::SigningKey::generate(&mut rand::rngs::OsRng);
+                let mut entity = chain.entities["AVA"].clone();
+                entity.name = name.to_string();
+                entity.constitutional_constraints = Vec::new();
+                entity.signing_keys = vec![hex::encode(key.verifying_key().to_bytes())];
+                chain.entities.insert(name.to_string(), entity);
+                key
+            })
+            .collect();
+        let message = crate::blockchain::governance_signing_payload(&authorized);
+        authorized.authenticator = Some(crate::blockchain::TransactionAuthenticator::MultiEd25519 {
+            public_keys: co_signers.iter().map(|key| key.verifying_key().to_bytes()).collect(),
+            signatures: co_signers.iter().map(|key| ed25519_dalek::Signer::sign(key, &message).to_bytes()).collect(),
+            threshold: co_signers.len(),
+        });
+
+        let block = chain
+            .apply_epoch_transition(
+                authorized,
+                ValidatorChange::Add("perception-layer".to_string()),
+                &mut rand::rngs::OsRng,
+            )
+            .unwrap();
+
+        let validators = chain.active_validators(block.index);
+        assert!(validators.contains(&"perception-layer".to_string()));
+    }
+}
@@ -0,0 +1,197 @@
+// Generic three-stage verification pipeline shared by `block_queue` and
+// `tx_queue`: `unverified -> verifying -> verified`, backed by a pool of
+// worker threads. `BlockQueue` and `TransactionQueue` are thin, typed
+// wrappers over `VerifyQueue<Block>`/`VerifyQueue<Transaction>` so the
+// concurrency machinery exists exactly once, parameterized over the item
+// type and an `id_of` closure that extracts whatever field (`hash`, `id`,
+// ...) de-dup and in-flight tracking key off of.
+
+use std::collections::{HashSet, VecDeque};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::{self, JoinHandle};
+
+/// Signature of the verification function run by every worker, supplied by
+/// whoever owns the queue (usually `AvaBlockchain`).
+pub type Verifier<T> = Arc<dyn Fn(&T) -> Result<(), String> + Send + Sync>;
+
+/// Extracts the de-dup/in-flight-tracking key (a block's `hash`, a
+/// transaction's `id`, ...) from an item.
+pub type IdOf<T> = Arc<dyn Fn(&T) -> String + Send + Sync>;
+
+/// Snapshot of how much work is sitting in each stage of the pipeline
+#[derive(Debug, Clone, Copy, Default)]
+pub struct QueueInfo {
+    pub unverified: usize,
+    pub verifying: usize,
+    pub verified: usize,
+}
+
+impl QueueInfo {
+    pub fn total_queue_size(&self) -> usize {
+        self.unverified + self.verifying + self.verified
+    }
+
+    /// Work that has not yet made it into the `verified` stage
+    pub fn incomplete_queue_size(&self) -> usize {
+        self.unverified + self.verifying
+    }
+}
+
+struct Shared<T> {
+    /// Items waiting to be picked up by a worker, in arrival order
+    unverified: VecDeque<T>,
+    /// Keys currently being checked by a worker (dedup against re-queueing)
+    verifying: HashSet<String>,
+    /// Items that passed verification, paired with their arrival index so
+    /// the caller can replay them in the original order
+    verified: Vec<(usize, T)>,
+    /// Keys already seen (queued, verifying, or verified) so the same item
+    /// is never processed twice
+    seen: HashSet<String>,
+    next_index: usize,
+    shutdown: bool,
+}
+
+/// Concurrent, typed verification pipeline: `unverified -> verifying ->
+/// verified`. Callers only ever pull from `verified`, in arrival order.
+pub struct VerifyQueue<T> {
+    shared: Arc<Mutex<Shared<T>>>,
+    /// Signalled whenever an item is pushed, to wake a sleeping worker
+    more_to_verify: Arc<Condvar>,
+    /// Signalled whenever the queue becomes fully drained (nothing
+    /// unverified or verifying), so callers can block until empty
+    empty: Arc<Condvar>,
+    workers: Vec<JoinHandle<()>>,
+    id_of: IdOf<T>,
+}
+
+impl<T: Send + 'static> VerifyQueue<T> {
+    pub fn with_worker_count(workers: usize, verifier: Verifier<T>, id_of: IdOf<T>) -> Self {
+        let shared = Arc::new(Mutex::new(Shared {
+            unverified: VecDeque::new(),
+            verifying: HashSet::new(),
+            verified: Vec::new(),
+            seen: HashSet::new(),
+            next_index: 0,
+            shutdown: false,
+        }));
+        let more_to_verify = Arc::new(Condvar::new());
+        let empty = Arc::new(Condvar::new());
+
+        let handles = (0..workers.max(1))
+            .map(|_| {
+                let shared = Arc::clone(&shared);
+                let more_to_verify = Arc::clone(&more_to_verify);
+                let empty = Arc::clone(&empty);
+                let verifier = Arc::clone(&verifier);
+                let id_of = Arc::clone(&id_of);
+                thread::spawn(move || worker_loop(shared, more_to_verify, empty, verifier, id_of))
+            })
+            .collect();
+
+        VerifyQueue {
+            shared,
+            more_to_verify,
+            empty,
+            workers: handles,
+            id_of,
+        }
+    }
+
+    /// Enqueue an item for verification. An item whose key is already
+    /// queued, in flight, or verified is silently ignored.
+    pub fn push(&self, item: T) {
+        let mut shared = self.shared.lock().unwrap();
+        if !shared.seen.insert((self.id_of)(&item)) {
+            return;
+        }
+        shared.unverified.push_back(item);
+        self.more_to_verify.notify_one();
+    }
+
+    /// Drain every verified item, in the order it was originally pushed
+    pub fn drain_verified(&self) -> Vec<T> {
+        let mut shared = self.shared.lock().unwrap();
+        shared.verified.sort_by_key(|(index, _)| *index);
+        shared.verified.drain(..).map(|(_, item)| item).collect()
+    }
+
+    pub fn info(&self) -> QueueInfo {
+        let shared = self.shared.lock().unwrap();
+        QueueInfo {
+            unverified: shared.unverified.len(),
+            verifying: shared.verifying.len(),
+            verified: shared.verified.len(),
+        }
+    }
+
+    /// Block the calling thread until no work remains unverified or
+    /// in-flight (verified results may still be waiting to be drained)
+    pub fn wait_until_empty(&self) {
+        let shared = self.shared.lock().unwrap();
+        let _guard = self
+            .empty
+            .wait_while(shared, |s| !s.unverified.is_empty() || !s.verifying.is_empty())
+            .unwrap();
+    }
+}
+
+impl<T> Drop for VerifyQueue<T> {
+    fn drop(&mut self) {
+        {
+            let mut shared = self.shared.lock().unwrap();
+            shared.shutdown = true;
+        }
+        self.more_to_verify.notify_all();
+        for handle in self.workers.drain(..) {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn worker_loop<T: Send + 'static>(
+    shared: Arc<Mutex<Shared<T>>>,
+    more_to_verify: Arc<Condvar>,
+    empty: Arc<Condvar>,
+    verifier: Verifier<T>,
+    id_of: IdOf<T>,
+) {
+    loop {
+        let (item, key, index) = {
+            let mut guard = shared.lock().unwrap();
+            loop {
+                if guard.shutdown {
+                    return;
+                }
+                if let Some(item) = guard.unverified.pop_front() {
+                    let key = id_of(&item);
+                    guard.verifying.insert(key.clone());
+                    let index = guard.next_index;
+                    guard.next_index += 1;
+                    break (item, key, index);
+                }
+                guard = more_to_verify.wait(guard).unwrap();
+            }
+        };
+
+        let result = verifier(&item);
+
+        let mut guard = shared.lock().unwrap();
+        guard.verifying.remove(&key);
+        if result.is_ok() {
+            guard.verified.push((index, item));
+        } else {
+            // Failed items are dropped from `seen` too, so a corrected
+            // resubmission of the same key is not silently ignored.
+            guard.seen.remove(&key);
+        }
+        if guard.unverified.is_empty() && guard.verifying.is_empty() {
+            empty.notify_all();
+        }
+    }
+}
+
+pub(crate) fn worker_count() -> usize {
+    let cpus = thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+    cpus.max(3) - 2
+}
@@ -1,32 +1,160 @@
 // This file contains the implementation of the tunnel functionality for the client.
+//
+// `send`/`receive` are raw, fire-and-forget UDP with a hardcoded 1024-byte
+// buffer: anything larger than that is silently truncated, and a dropped
+// packet is gone for good. `send_reliable`/`receive_message` add a
+// reliability layer on top of the same socket: outbound payloads are split
+// into fragments tagged with a message id and fragment index/count, the
+// receive side reassembles them keyed by message id, and unacked fragments
+// are retransmitted with exponential backoff until the sender sees a
+// selective ack or gives up.
 
+use std::collections::HashMap;
 use std::net::{IpAddr, Ipv4Addr, SocketAddr};
-use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::net::UdpSocket;
+use tokio::sync::{mpsc, Mutex};
 use tokio::task;
+use tokio::time::sleep;
+
+/// Conservative UDP payload ceiling: `receive` reads at most this many
+/// bytes per datagram, and `send_reliable` fragments against it. `send`
+/// does not check it.
+const MTU: usize = 1024;
+const HEADER_LEN: usize = 9;
+const MAX_FRAGMENT_PAYLOAD: usize = MTU - HEADER_LEN;
+const INITIAL_RETRANSMIT_DELAY: Duration = Duration::from_millis(200);
+const MAX_RETRANSMITS: u32 = 6;
+
+/// Upper bound on distinct message ids the receive loop will hold partial
+/// reassembly state for at once. `Data` packets carry no sender
+/// authentication, so without this an attacker can spoof an unbounded
+/// number of message ids and grow `pending` forever.
+const MAX_PENDING_MESSAGES: usize = 256;
+/// Upper bound on the total fragment slots reserved across every pending
+/// message's `fragments: Vec<Option<Vec<u8>>>`. `fragment_count` is taken
+/// from the packet as-is (up to 65535), so this is what actually caps the
+/// reassembly buffer's worst-case size, in concert with
+/// `MAX_PENDING_MESSAGES` above -- roughly `MAX_PENDING_FRAGMENT_SLOTS *
+/// MAX_FRAGMENT_PAYLOAD` bytes of reassembly storage outstanding.
+const MAX_PENDING_FRAGMENT_SLOTS: usize = 65536;
+/// How long an incomplete reassembly may sit in `pending` before it's
+/// evicted to make room for new messages.
+const PENDING_MESSAGE_TTL: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PacketKind {
+    Data,
+    Ack,
+}
+
+impl PacketKind {
+    fn as_byte(self) -> u8 {
+        match self {
+            PacketKind::Data => 0,
+            PacketKind::Ack => 1,
+        }
+    }
+
+    fn from_byte(byte: u8) -> Result<Self, String> {
+        match byte {
+            0 => Ok(PacketKind::Data),
+            1 => Ok(PacketKind::Ack),
+            other => Err(format!("unknown tunnel packet kind {}", other)),
+        }
+    }
+}
+
+/// Fixed-size header prefixed to every reliable packet: a message id shared
+/// by every fragment of one logical message, this fragment's index and the
+/// total fragment count (both meaningful only for `Data` packets), and the
+/// packet kind.
+struct Header {
+    message_id: u32,
+    fragment_index: u16,
+    fragment_count: u16,
+    kind: PacketKind,
+}
+
+impl Header {
+    fn encode(&self) -> [u8; HEADER_LEN] {
+        let mut bytes = [0u8; HEADER_LEN];
+        bytes[0..4].copy_from_slice(&self.message_id.to_be_bytes());
+        bytes[4..6].copy_from_slice(&self.fragment_index.to_be_bytes());
+        bytes[6..8].copy_from_slice(&self.fragment_count.to_be_bytes());
+        bytes[8] = self.kind.as_byte();
+        bytes
+    }
+
+    fn decode(bytes: &[u8]) -> Result<Self, String> {
+        if bytes.len() < HEADER_LEN {
+            return Err("tunnel packet shorter than header".to_string());
+        }
+        Ok(Header {
+            message_id: u32::from_be_bytes(bytes[0..4].try_into().unwrap()),
+            fragment_index: u16::from_be_bytes(bytes[4..6].try_into().unwrap()),
+            fragment_count: u16::from_be_bytes(bytes[6..8].try_into().unwrap()),
+            kind: PacketKind::from_byte(bytes[8])?,
+        })
+    }
+}
+
+/// Fragments of one logical message awaiting reassembly from the peer.
+struct PendingReceive {
+    fragments: Vec<Option<Vec<u8>>>,
+    received: usize,
+    first_seen: Instant,
+}
+
+/// Removes every `pending` entry older than `PENDING_MESSAGE_TTL`, freeing
+/// the fragment slots they reserved so a sender that never completes a
+/// message can't hold its share of the cap forever.
+fn evict_expired_pending(pending: &mut HashMap<u32, PendingReceive>, reserved_slots: &mut usize) {
+    pending.retain(|_, entry| {
+        if entry.first_seen.elapsed() < PENDING_MESSAGE_TTL {
+            true
+        } else {
+            *reserved_slots -= entry.fragments.len();
+            false
+        }
+    });
+}
+
+type AckListeners = Arc<Mutex<HashMap<u32, mpsc::UnboundedSender<Vec<u16>>>>>;
 
 pub struct Tunnel {
-    socket: Arc<Mutex<UdpSocket>>,
+    socket: Arc<UdpSocket>,
+    next_message_id: AtomicU32,
+    incoming: Mutex<mpsc::UnboundedReceiver<Vec<u8>>>,
+    ack_listeners: AckListeners,
 }
 
 impl Tunnel {
-    pub fn new(bind_addr: &str) -> Self {
-        let socket = UdpSocket::bind(bind_addr).await.unwrap();
+    pub async fn new(bind_addr: &str) -> Self {
+        let socket = Arc::new(UdpSocket::bind(bind_addr).await.unwrap());
+        let (message_tx, message_rx) = mpsc::unbounded_channel();
+        let ack_listeners: AckListeners = Arc::new(Mutex::new(HashMap::new()));
+
+        task::spawn(Self::run_receive_loop(socket.clone(), message_tx, ack_listeners.clone()));
+
         Tunnel {
-            socket: Arc::new(Mutex::new(socket)),
+            socket,
+            next_message_id: AtomicU32::new(0),
+            incoming: Mutex::new(message_rx),
+            ack_listeners,
         }
     }
 
     pub async fn send(&self, addr: IpAddr, port: u16, data: &[u8]) {
-        let socket = self.socket.lock().unwrap();
         let target = SocketAddr::new(addr, port);
-        socket.send_to(data, target).await.unwrap();
+        self.socket.send_to(data, target).await.unwrap();
     }
 
     pub async fn receive(&self) -> (Vec<u8>, SocketAddr) {
-        let socket = self.socket.lock().unwrap();
-        let mut buf = vec![0; 1024];
-        let (len, addr) = socket.recv_from(&mut buf).await.unwrap();
+        let mut buf = vec![0; MTU];
+        let (len, addr) = self.socket.recv_from(&mut buf).await.unwrap();
         buf.truncate(len);
         (buf, addr)
     }
@@ -36,8 +164,193 @@ impl Tunnel {
             let (data, addr) = self.receive().await;
             // Process received data
             task::spawn(async move {
+                let _ = (data, addr);
                 // Handle data from addr
             });
         }
     }
-}
\ No newline at end of file
+
+    /// Splits `data` into MTU-sized fragments, sends them to `addr:port`,
+    /// and retransmits any fragment the peer hasn't selectively acked yet
+    /// with exponential backoff, giving up after `MAX_RETRANSMITS` rounds.
+    pub async fn send_reliable(&self, addr: IpAddr, port: u16, data: &[u8]) -> Result<(), String> {
+        let target = SocketAddr::new(addr, port);
+        let message_id = self.next_message_id.fetch_add(1, Ordering::Relaxed);
+
+        let fragments: Vec<&[u8]> = if data.is_empty() {
+            vec![&data[0..0]]
+        } else {
+            data.chunks(MAX_FRAGMENT_PAYLOAD).collect()
+        };
+        let fragment_count = fragments.len() as u16;
+
+        let mut unacked: std::collections::HashSet<u16> = (0..fragment_count).collect();
+        let packets: Vec<Vec<u8>> = fragments
+            .iter()
+            .enumerate()
+            .map(|(index, payload)| {
+                let header = Header {
+                    message_id,
+                    fragment_index: index as u16,
+                    fragment_count,
+                    kind: PacketKind::Data,
+                };
+                [header.encode().as_slice(), payload].concat()
+            })
+            .collect();
+
+        let mut ack_rx = self.register_ack_listener(message_id).await;
+        let mut delay = INITIAL_RETRANSMIT_DELAY;
+
+        for attempt in 0..=MAX_RETRANSMITS {
+            for &index in unacked.iter() {
+                self.socket
+                    .send_to(&packets[index as usize], target)
+                    .await
+                    .map_err(|e| format!("failed to send fragment {} of message {}: {}", index, message_id, e))?;
+            }
+
+            if attempt == MAX_RETRANSMITS {
+                break;
+            }
+
+            let wait_for_acks = async {
+                while !unacked.is_empty() {
+                    match ack_rx.recv().await {
+                        Some(acked_indices) => {
+                            for index in acked_indices {
+                                unacked.remove(&index);
+                            }
+                        }
+                        None => break,
+                    }
+                }
+            };
+
+            let _ = tokio::time::timeout(delay, wait_for_acks).await;
+            if unacked.is_empty() {
+                break;
+            }
+            delay *= 2;
+        }
+
+        self.unregister_ack_listener(message_id).await;
+
+        if unacked.is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "message {} gave up after {} retransmits with {} fragment(s) unacked",
+                message_id,
+                MAX_RETRANSMITS,
+                unacked.len()
+            ))
+        }
+    }
+
+    /// Yields only fully reassembled messages, in the order their last
+    /// fragment arrived. Raw `Data`/`Ack` framing and partial reassembly
+    /// are handled by the background receive loop; this just drains the
+    /// completed-message channel.
+    pub async fn receive_message(&self) -> Vec<u8> {
+        let mut incoming = self.incoming.lock().await;
+        incoming.recv().await.expect("tunnel receive loop exited")
+    }
+
+    async fn register_ack_listener(&self, message_id: u32) -> mpsc::UnboundedReceiver<Vec<u16>> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.ack_listeners.lock().await.insert(message_id, tx);
+        rx
+    }
+
+    async fn unregister_ack_listener(&self, message_id: u32) {
+        self.ack_listeners.lock().await.remove(&message_id);
+    }
+
+    /// Background task owning the socket's receive side: demultiplexes
+    /// incoming `Ack` packets to whichever `send_reliable` call is waiting
+    /// on them, reassembles `Data` fragments per message id, and acks each
+    /// fragment back to its sender as it arrives.
+    async fn run_receive_loop(socket: Arc<UdpSocket>, completed: mpsc::UnboundedSender<Vec<u8>>, ack_listeners: AckListeners) {
+        let mut pending: HashMap<u32, PendingReceive> = HashMap::new();
+        let mut reserved_fragment_slots: usize = 0;
+        let mut buf = vec![0u8; MTU];
+
+        loop {
+            let (len, src) = match socket.recv_from(&mut buf).await {
+                Ok(pair) => pair,
+                Err(_) => continue,
+            };
+            let packet = &buf[..len];
+            let header = match Header::decode(packet) {
+                Ok(header) => header,
+                Err(_) => continue,
+            };
+            let payload = &packet[HEADER_LEN..];
+
+            match header.kind {
+                PacketKind::Ack => {
+                    // Acks delivered out-of-band to whichever `send_reliable`
+                    // call registered a listener for this message id.
+                    let acked: Vec<u16> = payload
+                        .chunks(2)
+                        .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+                        .collect();
+                    if let Some(tx) = ack_listeners.lock().await.get(&header.message_id) {
+                        let _ = tx.send(acked);
+                    }
+                }
+                PacketKind::Data => {
+                    evict_expired_pending(&mut pending, &mut reserved_fragment_slots);
+
+                    if !pending.contains_key(&header.message_id) {
+                        let slots_if_admitted =
+                            reserved_fragment_slots + header.fragment_count as usize;
+                        if pending.len() >= MAX_PENDING_MESSAGES
+                            || slots_if_admitted > MAX_PENDING_FRAGMENT_SLOTS
+                        {
+                            // Unauthenticated `Data` packets can claim any
+                            // message id and fragment_count they like; once
+                            // the reassembly buffer is full, silently drop
+                            // new message ids rather than grow `pending`
+                            // without bound. A genuine sender just retries.
+                            continue;
+                        }
+                        reserved_fragment_slots = slots_if_admitted;
+                    }
+
+                    let entry = pending.entry(header.message_id).or_insert_with(|| PendingReceive {
+                        fragments: vec![None; header.fragment_count as usize],
+                        received: 0,
+                        first_seen: Instant::now(),
+                    });
+
+                    if (header.fragment_index as usize) < entry.fragments.len()
+                        && entry.fragments[header.fragment_index as usize].is_none()
+                    {
+                        entry.fragments[header.fragment_index as usize] = Some(payload.to_vec());
+                        entry.received += 1;
+                    }
+
+                    let ack = Header {
+                        message_id: header.message_id,
+                        fragment_index: 0,
+                        fragment_count: 0,
+                        kind: PacketKind::Ack,
+                    };
+                    let ack_payload = header.fragment_index.to_be_bytes();
+                    let ack_packet = [ack.encode().as_slice(), ack_payload.as_slice()].concat();
+                    let _ = socket.send_to(&ack_packet, src).await;
+
+                    if entry.received == entry.fragments.len() {
+                        let complete = pending.remove(&header.message_id).unwrap();
+                        reserved_fragment_slots -= complete.fragments.len();
+                        let message: Vec<u8> = complete.fragments.into_iter().flatten().flatten().collect();
+                        let _ = completed.send(message);
+                    }
+                }
+            }
+        }
+    }
+
+}
@@ -1,32 +1,130 @@
-// This file contains the implementation of zk-SNARK identity proofs.
+// zk-SNARK Identity Membership Circuit
+// Proves knowledge of a preimage `identity` such that `SHA256(identity)`
+// equals a publicly known commitment, without revealing `identity` itself.
+// The commitment is allocated as a public input so a verifier only ever
+// needs the Groth16 verifying key and the commitment bytes to check a
+// proof -- it never sees the preimage.
 
-mod snark_id {
-    use bellman::{Circuit, ConstraintSystem, SynthesisError};
-    use pairing::Engine;
-    use rand::Rng;
+use bellman::gadgets::boolean::{AllocatedBit, Boolean};
+use bellman::gadgets::multipack;
+use bellman::gadgets::sha256::sha256;
+use bellman::groth16::{
+    create_random_proof, generate_random_parameters, prepare_verifying_key, verify_proof as groth16_verify,
+    Parameters, PreparedVerifyingKey, Proof, VerifyingKey,
+};
+use bellman::{Circuit, ConstraintSystem, SynthesisError};
+use pairing::bls12_381::Bls12;
+use rand::Rng;
 
-    pub struct IdentityProof<E: Engine> {
-        pub identity: Vec<u8>,
-        pub proof: Vec<u8>,
-    }
+/// Membership circuit: takes `preimage` as a private witness and enforces
+/// that `SHA256(preimage)` matches the public commitment packed into the
+/// circuit's public inputs. `preimage` is `None` during `setup` (the
+/// circuit's structure doesn't depend on the witness values) and `Some`
+/// when a real proof is generated.
+pub struct IdentityProof {
+    pub preimage: Option<Vec<u8>>,
+}
+
+impl Circuit<Bls12> for IdentityProof {
+    fn synthesize<CS: ConstraintSystem<Bls12>>(self, cs: &mut CS) -> Result<(), SynthesisError> {
+        // Decompose the witness preimage into allocated bits, one byte at a
+        // time, most-significant-bit first, matching the bit order
+        // `sha256`'s gadget expects and `multipack` later re-packs from the
+        // commitment's public inputs.
+        let preimage_bits = match &self.preimage {
+            Some(bytes) => bytes
+                .iter()
+                .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1u8 == 1u8))
+                .map(Some)
+                .collect::<Vec<_>>(),
+            None => vec![None; self.preimage.as_ref().map(Vec::len).unwrap_or(32) * 8],
+        };
+
+        let preimage_bits = preimage_bits
+            .into_iter()
+            .enumerate()
+            .map(|(i, bit)| {
+                Ok(Boolean::from(AllocatedBit::alloc(
+                    cs.namespace(|| format!("preimage bit {}", i)),
+                    bit,
+                )?))
+            })
+            .collect::<Result<Vec<_>, SynthesisError>>()?;
 
-    impl<E: Engine> Circuit<E> for IdentityProof<E> {
-        fn synthesize<CS: ConstraintSystem<E>>(self, cs: &mut CS) -> Result<(), SynthesisError> {
-            // Implement the zk-SNARK circuit logic here
-            Ok(())
-        }
+        // Hash the private witness inside the circuit, then expose the
+        // digest bits as public inputs via `multipack`: the verifier packs
+        // the same commitment bytes the same way to compare against.
+        let digest_bits = sha256(cs.namespace(|| "sha256(preimage)"), &preimage_bits)?;
+        multipack::pack_into_inputs(cs.namespace(|| "pack commitment"), &digest_bits)
     }
+}
+
+/// Run the Groth16 trusted setup for the membership circuit, returning the
+/// proving parameters and the (unprepared) verifying key. The circuit
+/// shape is fixed (a 256-bit SHA-256 preimage), so `preimage: None` is
+/// enough to synthesize it for setup.
+pub fn setup(rng: &mut impl Rng) -> (Parameters<Bls12>, VerifyingKey<Bls12>) {
+    let params = generate_random_parameters::<Bls12, _, _>(
+        IdentityProof { preimage: None },
+        rng,
+    )
+    .expect("membership circuit parameter generation should not fail");
+    let vk = params.vk.clone();
+    (params, vk)
+}
+
+/// Prove knowledge of `identity` such that `SHA256(identity) == commitment`
+/// for the commitment implied by `identity` itself, using `params` from
+/// `setup`. `identity` must be 32 bytes, the preimage length `setup` fixes
+/// the circuit to; any other length does not match `params`.
+pub fn generate_proof(params: &Parameters<Bls12>, identity: Vec<u8>, rng: &mut impl Rng) -> Proof<Bls12> {
+    create_random_proof(
+        IdentityProof { preimage: Some(identity) },
+        params,
+        rng,
+    )
+    .expect("membership circuit proof generation should not fail")
+}
+
+/// Verify `proof` against `vk` and a commitment, where `commitment` is the
+/// raw SHA-256 digest (32 bytes) the prover claims to know a preimage for.
+pub fn verify_proof(vk: &VerifyingKey<Bls12>, commitment: &[u8; 32], proof: &Proof<Bls12>) -> bool {
+    let pvk: PreparedVerifyingKey<Bls12> = prepare_verifying_key(vk);
+    let commitment_bits = commitment
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1u8 == 1u8))
+        .collect::<Vec<_>>();
+    let public_inputs = multipack::compute_multipacking::<Bls12>(&commitment_bits);
+    groth16_verify(&pvk, proof, &public_inputs).is_ok()
+}
 
-    pub fn generate_proof<R: Rng>(identity: Vec<u8>, rng: &mut R) -> IdentityProof<E> {
-        // Logic to generate zk-SNARK proof for the given identity
-        IdentityProof {
-            identity,
-            proof: vec![], // Placeholder for the actual proof
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::OsRng;
+    use sha2::{Digest, Sha256};
+
+    #[test]
+    fn proof_verifies_against_the_preimage_s_own_commitment() {
+        let mut rng = OsRng;
+        let (params, vk) = setup(&mut rng);
+
+        let identity = b"artifact-virtual/ava-core/member".to_vec();
+        let commitment: [u8; 32] = Sha256::digest(&identity).into();
+
+        let proof = generate_proof(&params, identity, &mut rng);
+        assert!(verify_proof(&vk, &commitment, &proof));
     }
 
-    pub fn verify_proof(proof: &IdentityProof<E>) -> bool {
-        // Logic to verify the zk-SNARK proof
-        true // Placeholder for actual verification logic
+    #[test]
+    fn proof_is_rejected_against_a_mismatched_commitment() {
+        let mut rng = OsRng;
+        let (params, vk) = setup(&mut rng);
+
+        let identity = b"artifact-virtual/ava-core/member".to_vec();
+        let wrong_commitment: [u8; 32] = Sha256::digest(b"a different identity").into();
+
+        let proof = generate_proof(&params, identity, &mut rng);
+        assert!(!verify_proof(&vk, &wrong_commitment, &proof));
     }
-}
\ No newline at end of file
+}
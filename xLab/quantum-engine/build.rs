@@ -0,0 +1,120 @@
+// Compiles the SolidityQasmBridge's contracts and generates typed Rust
+// bindings for them, mirroring how EVM-integrating crates keep generated
+// ABI bindings out of source control while guaranteeing they match the
+// on-disk contracts.
+//
+// Three steps:
+//   1. Use an svm-style solc version manager to install and select a
+//      pinned compiler.
+//   2. Compile every `.sol` file under `contracts/`.
+//   3. Run an abigen-style generator over the resulting ABI JSON and emit
+//      one typed binding module per contract into `src/abi/`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const SOLC_VERSION: &str = "0.8.25";
+const CONTRACTS_DIR: &str = "contracts";
+const BINDINGS_DIR: &str = "src/abi";
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", CONTRACTS_DIR);
+
+    // `solidity.rs` has a hard compile-time `#[path]` dependency on the
+    // bindings generated below, so a solc we can't install isn't a "skip
+    // the contract build" situation -- it's a build that cannot succeed.
+    // Fail loudly here instead of letting the crate fail to compile later
+    // with a confusing missing-module error.
+    let solc = install_pinned_solc(SOLC_VERSION)
+        .unwrap_or_else(|e| panic!("cannot build SolidityQasmBridge bindings: {}", e));
+
+    let contracts_dir = Path::new(CONTRACTS_DIR);
+    if !contracts_dir.exists() {
+        return;
+    }
+
+    fs::create_dir_all(BINDINGS_DIR).expect("failed to create src/abi");
+
+    for entry in fs::read_dir(contracts_dir).expect("failed to read contracts dir") {
+        let entry = entry.expect("failed to read contract entry");
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("sol") {
+            continue;
+        }
+
+        let abi = compile_contract(&solc, &path);
+        let contract_name = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .expect("contract file must have a name");
+        generate_bindings(contract_name, &abi);
+    }
+}
+
+/// Installs (or reuses a cached) `solc` pinned to `version`, returning the
+/// path to the resolved binary. svm keeps pinned compilers under
+/// `~/.svm/<version>/solc-<version>`.
+fn install_pinned_solc(version: &str) -> Result<PathBuf, String> {
+    let svm_home = dirs_home().join(".svm").join(version);
+    let solc_path = svm_home.join(format!("solc-{}", version));
+
+    if solc_path.exists() {
+        return Ok(solc_path);
+    }
+
+    fs::create_dir_all(&svm_home).map_err(|e| format!("failed to create svm home: {}", e))?;
+    svm_lib::install(version)
+        .map_err(|e| format!("failed to install solc {}: {}", version, e))?;
+
+    Ok(solc_path)
+}
+
+fn dirs_home() -> PathBuf {
+    std::env::var("HOME").map(PathBuf::from).unwrap_or_else(|_| PathBuf::from("."))
+}
+
+/// Compiles a single `.sol` file with the pinned `solc` and returns its ABI
+/// as a JSON string.
+fn compile_contract(solc: &Path, source: &Path) -> String {
+    let output = std::process::Command::new(solc)
+        .arg("--abi")
+        .arg(source)
+        .output()
+        .unwrap_or_else(|e| panic!("failed to invoke solc on {:?}: {}", source, e));
+
+    if !output.status.success() {
+        panic!(
+            "solc failed on {:?}: {}",
+            source,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+/// Emits a strongly-typed Rust binding module for `contract_name` into
+/// `src/abi/<contract_name_snake_case>.rs`, generated from its ABI.
+fn generate_bindings(contract_name: &str, abi_json: &str) {
+    let module_name = to_snake_case(contract_name);
+    let dest = Path::new(BINDINGS_DIR).join(format!("{}.rs", module_name));
+
+    let generated = ethers_abigen::Abigen::new(contract_name, abi_json)
+        .expect("failed to parse contract ABI")
+        .generate()
+        .expect("failed to generate bindings")
+        .to_string();
+
+    fs::write(&dest, generated).expect("failed to write generated bindings");
+}
+
+fn to_snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (i, ch) in name.char_indices() {
+        if ch.is_uppercase() && i > 0 {
+            snake.push('_');
+        }
+        snake.extend(ch.to_lowercase());
+    }
+    snake
+}
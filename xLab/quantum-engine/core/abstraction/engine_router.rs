@@ -1,9 +1,20 @@
 // This file implements the engine router in Rust. It routes requests to the appropriate backend based on user input.
+//
+// `QuantumBackend::execute` used to take a raw job string and return
+// nothing, so there was no way to actually run a job end-to-end or compare
+// results across backends. It's now async and returns a typed
+// `ExecutionResult`; `EngineRouter::execute` routes and runs a job in one
+// call, and `execute_all` fans the same job out to every registered
+// backend concurrently for cross-validation.
 
 mod backends;
 
-use backends::{QiskitBackend, CirqBackend, QuTiPBackend, RustNativeBackend, BraketClient, SolidityQasmBridge};
+use async_trait::async_trait;
+use backends::{QuantumOperation, SolidityQasmBridge};
+use futures_util::future::join_all;
 use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
 
 pub struct EngineRouter {
     backends: HashMap<String, Box<dyn QuantumBackend>>,
@@ -11,23 +22,79 @@ pub struct EngineRouter {
 
 impl EngineRouter {
     pub fn new() -> Self {
-        let mut backends = HashMap::new();
-        backends.insert("qiskit".to_string(), Box::new(QiskitBackend::new()));
-        backends.insert("cirq".to_string(), Box::new(CirqBackend::new()));
-        backends.insert("qutip".to_string(), Box::new(QuTiPBackend::new()));
-        backends.insert("rust_native".to_string(), Box::new(RustNativeBackend::new()));
-        backends.insert("braket".to_string(), Box::new(BraketClient::new()));
+        let mut backends: HashMap<String, Box<dyn QuantumBackend>> = HashMap::new();
+        backends.insert("rust_native".to_string(), Box::new(QuantumOperation::new()));
         backends.insert("solidity".to_string(), Box::new(SolidityQasmBridge::new()));
 
+        // qiskit/cirq/qutip/braket aren't wired up in this tree yet — there's
+        // no client for any of them under `backends/` to register here.
+
         EngineRouter { backends }
     }
 
     pub fn route(&self, backend_name: &str) -> Option<&Box<dyn QuantumBackend>> {
         self.backends.get(backend_name)
     }
+
+    /// Routes `job` to `backend_name` and runs it in one call.
+    pub async fn execute(&self, backend_name: &str, job: QuantumJob) -> Result<ExecutionResult, BackendError> {
+        let backend = self
+            .route(backend_name)
+            .ok_or_else(|| BackendError::UnknownBackend(backend_name.to_string()))?;
+        backend.execute(job).await
+    }
+
+    /// Dispatches `job` to every registered backend concurrently, for
+    /// comparing results across backends.
+    pub async fn execute_all(&self, job: QuantumJob) -> HashMap<String, Result<ExecutionResult, BackendError>> {
+        let runs = self.backends.iter().map(|(name, backend)| {
+            let job = job.clone();
+            async move { (name.clone(), backend.execute(job).await) }
+        });
+
+        join_all(runs).await.into_iter().collect()
+    }
+}
+
+/// A circuit to run on some backend: an OpenQASM source string, how many
+/// shots to sample, and any backend-specific parameters (e.g. a Braket
+/// device ARN or a Qiskit optimization level).
+#[derive(Debug, Clone)]
+pub struct QuantumJob {
+    pub qasm: String,
+    pub shots: u32,
+    pub parameters: HashMap<String, String>,
 }
 
-pub trait QuantumBackend {
+/// The outcome of running a `QuantumJob` on one backend: a measurement
+/// histogram keyed by bitstring, how long the run took, and which backend
+/// produced it.
+#[derive(Debug, Clone)]
+pub struct ExecutionResult {
+    pub histogram: HashMap<String, u64>,
+    pub duration: Duration,
+    pub backend: String,
+}
+
+#[derive(Debug)]
+pub enum BackendError {
+    UnknownBackend(String),
+    ExecutionFailed(String),
+}
+
+impl fmt::Display for BackendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BackendError::UnknownBackend(name) => write!(f, "no backend registered under \"{}\"", name),
+            BackendError::ExecutionFailed(reason) => write!(f, "backend execution failed: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for BackendError {}
+
+#[async_trait]
+pub trait QuantumBackend: Send + Sync {
     fn initialize(&self);
-    fn execute(&self, job: &str);
-}
\ No newline at end of file
+    async fn execute(&self, job: QuantumJob) -> Result<ExecutionResult, BackendError>;
+}
@@ -0,0 +1,7 @@
+// This file re-exports the quantum-engine backends for the engine router.
+
+mod rust_native;
+mod solidity;
+
+pub use rust_native::QuantumOperation;
+pub use solidity::SolidityQasmBridge;
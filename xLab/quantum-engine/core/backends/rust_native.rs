@@ -1,6 +1,11 @@
 // This file contains Rust code for a native quantum backend.
 // It defines functions for quantum operations and interfacing with other backends.
 
+use crate::core::abstraction::engine_router::{BackendError, ExecutionResult, QuantumBackend, QuantumJob};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::time::Instant;
+
 pub struct QuantumOperation {
     // Define the structure for a quantum operation
 }
@@ -11,9 +16,31 @@ impl QuantumOperation {
         QuantumOperation {}
     }
 
-    pub fn execute(&self) {
-        // Implement the logic to execute a quantum operation
+    // Additional functions for quantum operations can be added here
+}
+
+#[async_trait]
+impl QuantumBackend for QuantumOperation {
+    fn initialize(&self) {
+        // Nothing to warm up; there's no simulator state to allocate yet.
     }
 
-    // Additional functions for quantum operations can be added here
-}
\ No newline at end of file
+    async fn execute(&self, job: QuantumJob) -> Result<ExecutionResult, BackendError> {
+        let start = Instant::now();
+
+        // No real circuit simulator is wired up here yet, so every shot is
+        // reported in the ground state rather than actually simulating
+        // `job.qasm`. Still a real, structurally valid histogram so callers
+        // can exercise `EngineRouter::execute`/`execute_all` end-to-end.
+        let qubit_count = job.qasm.matches("qreg").count().max(1);
+        let ground_state = "0".repeat(qubit_count);
+        let mut histogram = HashMap::new();
+        histogram.insert(ground_state, job.shots as u64);
+
+        Ok(ExecutionResult {
+            histogram,
+            duration: start.elapsed(),
+            backend: "rust_native".to_string(),
+        })
+    }
+}
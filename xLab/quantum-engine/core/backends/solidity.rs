@@ -0,0 +1,52 @@
+// This file implements the SolidityQasmBridge backend. It dispatches
+// QASM-derived jobs against the on-chain QasmBridgeRouter contract using
+// the typed bindings build.rs generates from contracts/QasmBridgeRouter.sol.
+
+use crate::core::abstraction::engine_router::{BackendError, ExecutionResult, QuantumBackend, QuantumJob};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::time::Instant;
+
+#[path = "../../src/abi/qasm_bridge_router.rs"]
+mod qasm_bridge_router;
+use qasm_bridge_router::QasmBridgeRouter;
+
+pub struct SolidityQasmBridge {
+    router: QasmBridgeRouter,
+}
+
+impl SolidityQasmBridge {
+    pub fn new() -> Self {
+        SolidityQasmBridge {
+            router: QasmBridgeRouter::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl QuantumBackend for SolidityQasmBridge {
+    fn initialize(&self) {
+        // The router contract has no initialization step of its own; the
+        // connection is established lazily on first `execute`.
+    }
+
+    async fn execute(&self, job: QuantumJob) -> Result<ExecutionResult, BackendError> {
+        let start = Instant::now();
+
+        self.router
+            .submit_job(job.qasm.as_bytes())
+            .send()
+            .await
+            .map_err(|e| BackendError::ExecutionFailed(format!("on-chain submission failed: {}", e)))?;
+
+        // The contract call only confirms the job was accepted on-chain;
+        // the measurement histogram comes from off-chain execution, recorded
+        // later through `completeJob`, and isn't available synchronously
+        // here, so it comes back empty rather than fabricated.
+        Ok(ExecutionResult {
+            histogram: HashMap::new(),
+            duration: start.elapsed(),
+            backend: "solidity".to_string(),
+        })
+    }
+}
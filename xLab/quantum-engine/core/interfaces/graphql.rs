@@ -1,25 +1,536 @@
 // This file defines the GraphQL API for the quantum engine.
 // It includes type definitions and resolvers for querying quantum data.
+//
+// The `Query` root also exposes a governance surface so external
+// dashboards have one typed endpoint for both quantum backends and AVA's
+// constitutional governance, rather than a second ad-hoc API. The
+// governance data itself lives in `ConstitutionalEngine`, a separate crate
+// this one doesn't depend on (same reasoning as `IdentityProofVerifier` in
+// the AVA-prototype chain crate), so resolvers go through the
+// `GovernanceStore` trait below and the concrete engine is injected as
+// `Arc<dyn GovernanceStore>` via the schema's `Context` data.
 
-use async_graphql::{Context, Object, Schema};
+use async_graphql::{Context, Enum, InputObject, Object, Result, Schema, SimpleObject};
+use async_trait::async_trait;
+use std::sync::Arc;
 
 pub struct Query;
 
 #[Object]
 impl Query {
-    async fn backend_status(&self, ctx: &Context<'_>) -> String {
+    async fn backend_status(&self, _ctx: &Context<'_>) -> String {
         // Placeholder for backend status query
         "All backends are operational".to_string()
     }
 
-    async fn list_backends(&self, ctx: &Context<'_>) -> Vec<String> {
+    async fn list_backends(&self, _ctx: &Context<'_>) -> Vec<String> {
         // Placeholder for listing available backends
         vec!["Qiskit".to_string(), "Cirq".to_string(), "QuTiP".to_string()]
     }
+
+    /// Proposals in the governance engine, optionally filtered to a single
+    /// `ProposalStatus`.
+    async fn proposals(&self, ctx: &Context<'_>, status: Option<ProposalStatus>) -> Result<Vec<GqlProposal>> {
+        Ok(governance(ctx)?.proposals(status).await)
+    }
+
+    async fn proposal(&self, ctx: &Context<'_>, id: String) -> Result<Option<GqlProposal>> {
+        Ok(governance(ctx)?.proposal(&id).await)
+    }
+
+    /// The resolved tally for a proposal, once it has one -- see
+    /// `ConstitutionalEngine::voting_records`.
+    async fn voting_record(&self, ctx: &Context<'_>, proposal_id: String) -> Result<Option<GqlVotingRecord>> {
+        Ok(governance(ctx)?.voting_record(&proposal_id).await)
+    }
+
+    async fn rules(&self, ctx: &Context<'_>, active_only: bool) -> Result<Vec<GqlGovernanceRule>> {
+        Ok(governance(ctx)?.rules(active_only).await)
+    }
+
+    /// The constitutional audit trail, most recent first, optionally
+    /// filtered to a single `EventType` and capped at `limit` entries.
+    async fn constitutional_history(
+        &self,
+        ctx: &Context<'_>,
+        limit: usize,
+        event_type: Option<EventType>,
+    ) -> Result<Vec<GqlConstitutionalEvent>> {
+        Ok(governance(ctx)?.constitutional_history(limit, event_type).await)
+    }
+}
+
+pub struct Mutation;
+
+#[Object]
+impl Mutation {
+    /// Submits a new proposal and returns its generated id.
+    async fn submit_proposal(&self, ctx: &Context<'_>, input: SubmitProposalInput) -> Result<String> {
+        governance(ctx)?.submit_proposal(input).await
+    }
+
+    /// Casts a vote on a proposal. Returns `true` once the vote is recorded.
+    async fn cast_vote(&self, ctx: &Context<'_>, input: CastVoteInput) -> Result<bool> {
+        governance(ctx)?.cast_vote(input).await?;
+        Ok(true)
+    }
+}
+
+/// Fetches the `Arc<dyn GovernanceStore>` installed in the schema's
+/// context data, so every resolver shares the one failure path if it was
+/// never wired up.
+fn governance<'a>(ctx: &Context<'a>) -> Result<&'a Arc<dyn GovernanceStore>> {
+    ctx.data::<Arc<dyn GovernanceStore>>()
+}
+
+/// Everything the GraphQL layer needs from `ConstitutionalEngine`,
+/// expressed as thin output/input types instead of the engine's own
+/// structs so this crate doesn't have to depend on it. An
+/// `Arc<dyn GovernanceStore>` wrapping a real `ConstitutionalEngine` is
+/// what a binary wiring this schema together would inject into the
+/// `Context`.
+#[async_trait]
+pub trait GovernanceStore: Send + Sync {
+    async fn proposals(&self, status: Option<ProposalStatus>) -> Vec<GqlProposal>;
+    async fn proposal(&self, id: &str) -> Option<GqlProposal>;
+    async fn voting_record(&self, proposal_id: &str) -> Option<GqlVotingRecord>;
+    async fn rules(&self, active_only: bool) -> Vec<GqlGovernanceRule>;
+    async fn constitutional_history(&self, limit: usize, event_type: Option<EventType>) -> Vec<GqlConstitutionalEvent>;
+    async fn submit_proposal(&self, input: SubmitProposalInput) -> Result<String>;
+    async fn cast_vote(&self, input: CastVoteInput) -> Result<()>;
+}
+
+/// Mirrors `constitutional::ProposalStatus`.
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum ProposalStatus {
+    Draft,
+    Preparing,
+    Deciding,
+    Confirming,
+    Approved,
+    Rejected,
+    Implemented,
+    TimedOut,
+    Cancelled,
+}
+
+/// Mirrors `constitutional::VoteType`.
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum VoteType {
+    For,
+    Against,
+    Abstain,
+}
+
+/// Mirrors `constitutional::EventType`.
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum EventType {
+    RuleCreation,
+    RuleModification,
+    RuleEnforcement,
+    ViolationDetected,
+    ProposalSubmitted,
+    VoteCast,
+    ProposalResolved,
+    EmergencyAction,
+    ConstitutionalAmendment,
+}
+
+/// Mirrors `constitutional::Proposal`.
+#[derive(SimpleObject, Clone)]
+pub struct GqlProposal {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub proposer: String,
+    pub status: ProposalStatus,
+    pub decision_starts_at: String,
+    pub decision_ends_at: String,
+    pub votes_cast: i32,
+}
+
+/// Mirrors `constitutional::VotingRecord`.
+#[derive(SimpleObject, Clone)]
+pub struct GqlVotingRecord {
+    pub proposal_id: String,
+    pub total_eligible_voters: i32,
+    pub total_votes_cast: i32,
+    pub votes_for: i32,
+    pub votes_against: i32,
+    pub votes_abstain: i32,
+    pub participation_rate: f32,
+}
+
+/// Mirrors `constitutional::GovernanceRule`.
+#[derive(SimpleObject, Clone)]
+pub struct GqlGovernanceRule {
+    pub id: String,
+    pub description: String,
+    pub active: bool,
+}
+
+/// Mirrors `constitutional::ConstitutionalEvent`.
+#[derive(SimpleObject, Clone)]
+pub struct GqlConstitutionalEvent {
+    pub id: String,
+    pub event_type: EventType,
+    pub description: String,
+    pub actor: String,
+    pub timestamp: String,
+}
+
+/// Maps onto `Proposal`/`ProposalType` for `submit_proposal`; the track
+/// timing fields aren't accepted here because `ConstitutionalEngine`
+/// derives them from the proposal type, not the caller.
+#[derive(InputObject)]
+pub struct SubmitProposalInput {
+    pub proposal_type: String,
+    pub title: String,
+    pub description: String,
+    pub proposer: String,
+    pub implementation_details: Option<String>,
+}
+
+/// Maps onto `Vote` for `cast_vote`. The signature fields are required
+/// because `ConstitutionalEngine::cast_vote` rejects an unsigned vote.
+#[derive(InputObject)]
+pub struct CastVoteInput {
+    pub proposal_id: String,
+    pub voter: String,
+    pub vote_type: VoteType,
+    pub weight: f32,
+    pub rationale: Option<String>,
+    pub governance_key: String,
+    pub signature: String,
 }
 
 // Function to create the GraphQL schema
-pub fn create_schema() -> Schema<Query, EmptyMutation, EmptySubscription> {
-    Schema::build(Query, EmptyMutation, EmptySubscription)
+pub fn create_schema(governance: Arc<dyn GovernanceStore>) -> Schema<Query, Mutation, async_graphql::EmptySubscription> {
+    Schema::build(Query, Mutation, async_graphql::EmptySubscription)
+        .data(governance)
         .finish()
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_graphql::Request;
+    use std::sync::Mutex;
+
+    /// A concrete, in-memory `GovernanceStore` standing in for an adapter
+    /// over a real `ConstitutionalEngine`, so every resolver and mutation
+    /// below has something to actually run against instead of staying
+    /// unexercised behind the trait.
+    #[derive(Default)]
+    struct InMemoryGovernanceStore {
+        proposals: Mutex<Vec<GqlProposal>>,
+        voting_records: Mutex<Vec<GqlVotingRecord>>,
+        rules: Mutex<Vec<GqlGovernanceRule>>,
+        history: Mutex<Vec<GqlConstitutionalEvent>>,
+        next_id: Mutex<u32>,
+    }
+
+    impl InMemoryGovernanceStore {
+        fn with_rule(id: &str, description: &str, active: bool) -> Self {
+            let store = Self::default();
+            store.rules.lock().unwrap().push(GqlGovernanceRule {
+                id: id.to_string(),
+                description: description.to_string(),
+                active,
+            });
+            store
+        }
+    }
+
+    #[async_trait]
+    impl GovernanceStore for InMemoryGovernanceStore {
+        async fn proposals(&self, status: Option<ProposalStatus>) -> Vec<GqlProposal> {
+            self.proposals
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|p| status.map_or(true, |s| p.status == s))
+                .cloned()
+                .collect()
+        }
+
+        async fn proposal(&self, id: &str) -> Option<GqlProposal> {
+            self.proposals.lock().unwrap().iter().find(|p| p.id == id).cloned()
+        }
+
+        async fn voting_record(&self, proposal_id: &str) -> Option<GqlVotingRecord> {
+            self.voting_records
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|r| r.proposal_id == proposal_id)
+                .cloned()
+        }
+
+        async fn rules(&self, active_only: bool) -> Vec<GqlGovernanceRule> {
+            self.rules
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|r| !active_only || r.active)
+                .cloned()
+                .collect()
+        }
+
+        async fn constitutional_history(&self, limit: usize, event_type: Option<EventType>) -> Vec<GqlConstitutionalEvent> {
+            self.history
+                .lock()
+                .unwrap()
+                .iter()
+                .rev()
+                .filter(|e| event_type.map_or(true, |t| e.event_type == t))
+                .take(limit)
+                .cloned()
+                .collect()
+        }
+
+        async fn submit_proposal(&self, input: SubmitProposalInput) -> Result<String> {
+            let mut next_id = self.next_id.lock().unwrap();
+            *next_id += 1;
+            let id = format!("prop_{}", *next_id);
+
+            self.proposals.lock().unwrap().push(GqlProposal {
+                id: id.clone(),
+                title: input.title,
+                description: input.description,
+                proposer: input.proposer,
+                status: ProposalStatus::Preparing,
+                decision_starts_at: "2026-01-01T00:00:00Z".to_string(),
+                decision_ends_at: "2026-01-08T00:00:00Z".to_string(),
+                votes_cast: 0,
+            });
+            self.history.lock().unwrap().push(GqlConstitutionalEvent {
+                id: format!("event_{}", id),
+                event_type: EventType::ProposalSubmitted,
+                description: format!("Proposal {} submitted", id),
+                actor: "test_user".to_string(),
+                timestamp: "2026-01-01T00:00:00Z".to_string(),
+            });
+
+            Ok(id)
+        }
+
+        async fn cast_vote(&self, input: CastVoteInput) -> Result<()> {
+            let mut proposals = self.proposals.lock().unwrap();
+            let proposal = proposals
+                .iter_mut()
+                .find(|p| p.id == input.proposal_id)
+                .ok_or_else(|| async_graphql::Error::new("proposal not found"))?;
+            proposal.votes_cast += 1;
+            drop(proposals);
+
+            self.history.lock().unwrap().push(GqlConstitutionalEvent {
+                id: format!("event_vote_{}", input.voter),
+                event_type: EventType::VoteCast,
+                description: format!("{} voted on {}", input.voter, input.proposal_id),
+                actor: input.voter,
+                timestamp: "2026-01-01T00:00:00Z".to_string(),
+            });
+
+            Ok(())
+        }
+    }
+
+    fn schema_with(store: InMemoryGovernanceStore) -> Schema<Query, Mutation, async_graphql::EmptySubscription> {
+        Schema::build(Query, Mutation, async_graphql::EmptySubscription)
+            .data(Arc::new(store) as Arc<dyn GovernanceStore>)
+            .finish()
+    }
+
+    #[tokio::test]
+    async fn proposals_resolver_lists_and_filters_by_status() {
+        let store = InMemoryGovernanceStore::default();
+        store.proposals.lock().unwrap().push(GqlProposal {
+            id: "p1".to_string(),
+            title: "Raise quorum".to_string(),
+            description: "desc".to_string(),
+            proposer: "alice".to_string(),
+            status: ProposalStatus::Deciding,
+            decision_starts_at: "2026-01-01T00:00:00Z".to_string(),
+            decision_ends_at: "2026-01-08T00:00:00Z".to_string(),
+            votes_cast: 2,
+        });
+        let schema = schema_with(store);
+
+        let response = schema.execute("{ proposals { id } }").await;
+        assert!(response.errors.is_empty());
+        let data = response.data.into_json().unwrap();
+        assert_eq!(data["proposals"][0]["id"], "p1");
+
+        let response = schema.execute(Request::new("{ proposals(status: APPROVED) { id } }")).await;
+        assert!(response.errors.is_empty());
+        let data = response.data.into_json().unwrap();
+        assert_eq!(data["proposals"].as_array().unwrap().len(), 0);
+    }
+
+    #[tokio::test]
+    async fn proposal_resolver_finds_by_id_or_returns_null() {
+        let store = InMemoryGovernanceStore::default();
+        store.proposals.lock().unwrap().push(GqlProposal {
+            id: "p1".to_string(),
+            title: "Raise quorum".to_string(),
+            description: "desc".to_string(),
+            proposer: "alice".to_string(),
+            status: ProposalStatus::Deciding,
+            decision_starts_at: "2026-01-01T00:00:00Z".to_string(),
+            decision_ends_at: "2026-01-08T00:00:00Z".to_string(),
+            votes_cast: 2,
+        });
+        let schema = schema_with(store);
+
+        let response = schema.execute(r#"{ proposal(id: "p1") { title } }"#).await;
+        assert!(response.errors.is_empty());
+        assert_eq!(response.data.into_json().unwrap()["proposal"]["title"], "Raise quorum");
+
+        let response = schema.execute(r#"{ proposal(id: "missing") { title } }"#).await;
+        assert!(response.errors.is_empty());
+        assert!(response.data.into_json().unwrap()["proposal"].is_null());
+    }
+
+    #[tokio::test]
+    async fn voting_record_resolver_returns_the_matching_record() {
+        let store = InMemoryGovernanceStore::default();
+        store.voting_records.lock().unwrap().push(GqlVotingRecord {
+            proposal_id: "p1".to_string(),
+            total_eligible_voters: 10,
+            total_votes_cast: 3,
+            votes_for: 2,
+            votes_against: 1,
+            votes_abstain: 0,
+            participation_rate: 0.3,
+        });
+        let schema = schema_with(store);
+
+        let response = schema.execute(r#"{ votingRecord(proposalId: "p1") { totalVotesCast } }"#).await;
+        assert!(response.errors.is_empty());
+        assert_eq!(response.data.into_json().unwrap()["votingRecord"]["totalVotesCast"], 3);
+    }
+
+    #[tokio::test]
+    async fn rules_resolver_filters_to_active_only() {
+        let store = InMemoryGovernanceStore::with_rule("r1", "must quorum", true);
+        store.rules.lock().unwrap().push(GqlGovernanceRule {
+            id: "r2".to_string(),
+            description: "retired rule".to_string(),
+            active: false,
+        });
+        let schema = schema_with(store);
+
+        let response = schema.execute("{ rules(activeOnly: true) { id } }").await;
+        assert!(response.errors.is_empty());
+        let data = response.data.into_json().unwrap();
+        assert_eq!(data["rules"].as_array().unwrap().len(), 1);
+        assert_eq!(data["rules"][0]["id"], "r1");
+
+        let response = schema.execute("{ rules(activeOnly: false) { id } }").await;
+        assert!(response.errors.is_empty());
+        assert_eq!(response.data.into_json().unwrap()["rules"].as_array().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn constitutional_history_resolver_honors_limit_and_event_type() {
+        let store = InMemoryGovernanceStore::default();
+        {
+            let mut history = store.history.lock().unwrap();
+            history.push(GqlConstitutionalEvent {
+                id: "e1".to_string(),
+                event_type: EventType::ProposalSubmitted,
+                description: "submitted".to_string(),
+                actor: "alice".to_string(),
+                timestamp: "2026-01-01T00:00:00Z".to_string(),
+            });
+            history.push(GqlConstitutionalEvent {
+                id: "e2".to_string(),
+                event_type: EventType::VoteCast,
+                description: "voted".to_string(),
+                actor: "bob".to_string(),
+                timestamp: "2026-01-02T00:00:00Z".to_string(),
+            });
+        }
+        let schema = schema_with(store);
+
+        let response = schema
+            .execute("{ constitutionalHistory(limit: 10, eventType: VOTE_CAST) { id } }")
+            .await;
+        assert!(response.errors.is_empty());
+        let data = response.data.into_json().unwrap();
+        assert_eq!(data["constitutionalHistory"].as_array().unwrap().len(), 1);
+        assert_eq!(data["constitutionalHistory"][0]["id"], "e2");
+    }
+
+    #[tokio::test]
+    async fn submit_proposal_mutation_creates_and_returns_an_id() {
+        let schema = schema_with(InMemoryGovernanceStore::default());
+
+        let response = schema
+            .execute(
+                r#"mutation {
+                    submitProposal(input: {
+                        proposalType: "RuleAddition",
+                        title: "Raise quorum",
+                        description: "desc",
+                        proposer: "alice"
+                    })
+                }"#,
+            )
+            .await;
+
+        assert!(response.errors.is_empty());
+        let id = response.data.into_json().unwrap()["submitProposal"].as_str().unwrap().to_string();
+        assert!(id.starts_with("prop_"));
+    }
+
+    #[tokio::test]
+    async fn cast_vote_mutation_records_the_vote_and_rejects_an_unknown_proposal() {
+        let store = InMemoryGovernanceStore::default();
+        store.proposals.lock().unwrap().push(GqlProposal {
+            id: "p1".to_string(),
+            title: "Raise quorum".to_string(),
+            description: "desc".to_string(),
+            proposer: "alice".to_string(),
+            status: ProposalStatus::Deciding,
+            decision_starts_at: "2026-01-01T00:00:00Z".to_string(),
+            decision_ends_at: "2026-01-08T00:00:00Z".to_string(),
+            votes_cast: 0,
+        });
+        let schema = schema_with(store);
+
+        let response = schema
+            .execute(
+                r#"mutation {
+                    castVote(input: {
+                        proposalId: "p1",
+                        voter: "bob",
+                        voteType: FOR,
+                        weight: 1.0,
+                        governanceKey: "key",
+                        signature: "sig"
+                    })
+                }"#,
+            )
+            .await;
+        assert!(response.errors.is_empty());
+        assert_eq!(response.data.into_json().unwrap()["castVote"], true);
+
+        let response = schema
+            .execute(
+                r#"mutation {
+                    castVote(input: {
+                        proposalId: "missing",
+                        voter: "bob",
+                        voteType: FOR,
+                        weight: 1.0,
+                        governanceKey: "key",
+                        signature: "sig"
+                    })
+                }"#,
+            )
+            .await;
+        assert!(!response.errors.is_empty());
+    }
+}